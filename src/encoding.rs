@@ -0,0 +1,113 @@
+//! # Canonical Encoding Module
+//!
+//! Provides a stable byte encoding for hashing and storing values.
+//! This is a PRODUCTION-READY implementation with:
+//! - Fixed-width little-endian integers
+//! - Little-endian `u64` length prefixes for variable-length data
+//! - Strict decoding that rejects truncated or trailing bytes
+//!
+//! The format is part of the on-chain contract and never changes between
+//! versions:
+//!
+//! | Type | Encoding |
+//! |------|----------|
+//! | `u8`, `u16`, `u32`, `u64`, `u128`, `i64` | Little-endian, fixed width |
+//! | `bool` | One byte, `0` or `1` |
+//! | [`String`](crate::String) | `u64` byte length, then the raw bytes |
+//! | [`Vector<T>`](crate::Vector) | `u64` element count, then each element in order |
+
+/// Types with a canonical byte encoding
+///
+/// Equal values always produce identical bytes, independent of any serde
+/// backend.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::Encode;
+///
+/// let mut out = Vec::new();
+/// 258u32.encode(&mut out);
+/// assert_eq!(out, [2, 1, 0, 0]);
+///
+/// let mut input = &out[..];
+/// assert_eq!(u32::decode(&mut input), Ok(258));
+/// assert!(input.is_empty());
+/// ```
+pub trait Encode: Sized {
+    /// Append the encoding of `self` to `out`
+    fn encode(&self, out: &mut Vec<u8>);
+
+    /// Decode a value from the front of `input`, advancing past it
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(value)` - The decoded value
+    /// * `Err(String)` - If `input` is truncated or malformed
+    fn decode(input: &mut &[u8]) -> Result<Self, String>;
+}
+
+/// Split `len` bytes off the front of `input`
+pub(crate) fn take<'a>(input: &mut &'a [u8], len: usize) -> Result<&'a [u8], String> {
+    if input.len() < len {
+        return Err(format!(
+            "Unexpected end of input: need {} bytes, have {}",
+            len,
+            input.len()
+        ));
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Ok(head)
+}
+
+/// Decode a `u64` length prefix as a `usize`
+pub(crate) fn decode_len(input: &mut &[u8]) -> Result<usize, String> {
+    let len = u64::decode(input)?;
+    usize::try_from(len).map_err(|_| format!("Length prefix too large: {}", len))
+}
+
+/// Decode a value that must span all of `bytes`
+pub(crate) fn decode_exact<T: Encode>(bytes: &[u8]) -> Result<T, String> {
+    let mut input = bytes;
+    let value = T::decode(&mut input)?;
+    if !input.is_empty() {
+        return Err(format!("Trailing bytes after value: {}", input.len()));
+    }
+    Ok(value)
+}
+
+macro_rules! impl_encode_int {
+    ($($ty:ty),*) => {
+        $(
+            impl Encode for $ty {
+                fn encode(&self, out: &mut Vec<u8>) {
+                    out.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(input: &mut &[u8]) -> Result<Self, String> {
+                    let bytes = take(input, std::mem::size_of::<$ty>())?;
+                    let mut buf = [0u8; std::mem::size_of::<$ty>()];
+                    buf.copy_from_slice(bytes);
+                    Ok(<$ty>::from_le_bytes(buf))
+                }
+            }
+        )*
+    };
+}
+
+impl_encode_int!(u8, u16, u32, u64, u128, i64);
+
+impl Encode for bool {
+    fn encode(&self, out: &mut Vec<u8>) {
+        out.push(*self as u8);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        match u8::decode(input)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            b => Err(format!("Invalid bool byte: {}", b)),
+        }
+    }
+}
@@ -55,8 +55,16 @@ pub mod string;
 /// - Random number generation
 pub mod math;
 
+/// Canonical byte encoding.
+///
+/// Provides the [`Encode`] trait and its stable format for:
+/// - Fixed-width integers
+/// - Length-prefixed strings and vectors
+pub mod encoding;
+
+pub use encoding::Encode;
 pub use math::*;
-pub use object::{ObjectMetadata, ObjectRef, Owner};
+pub use object::{compute_digest, MultisigPolicy, ObjectMetadata, ObjectRef, Owner};
 pub use option::Option;
 pub use string::String;
 pub use vector::Vector;
@@ -6,6 +6,16 @@
 //! - Trigonometric functions
 //! - Logarithmic functions
 //! - Random number generation
+//!
+//! ## Floating-point functions
+//!
+//! The `f64` helpers (`powf_f64`, `exp_f64`, the trigonometric and
+//! logarithmic functions, and so on) defer to the platform math library,
+//! whose results are not guaranteed to be bit-identical across platforms.
+//! Use them only for off-chain modeling, never for consensus logic; use the
+//! integer functions or [`Fixed64`] for anything that must be deterministic.
+
+use crate::vector::Vector;
 
 /// Mathematical constants
 pub mod constants {
@@ -34,6 +44,74 @@ pub fn abs_i64(x: i64) -> u64 {
     }
 }
 
+/// Convert a signed integer to unsigned without wrapping
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// * `Some(x)` - If `x` is non-negative
+/// * `None` - If `x` is negative
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::i64_to_u64;
+///
+/// assert_eq!(i64_to_u64(0), Some(0));
+/// assert_eq!(i64_to_u64(i64::MAX), Some(i64::MAX as u64));
+/// assert_eq!(i64_to_u64(-1), None);
+/// assert_eq!(i64_to_u64(i64::MIN), None);
+/// ```
+pub fn i64_to_u64(x: i64) -> Option<u64> {
+    u64::try_from(x).ok()
+}
+
+/// Convert an unsigned integer to signed without wrapping
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// * `Some(x)` - If `x <= i64::MAX`
+/// * `None` - If `x` does not fit in an `i64`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::u64_to_i64;
+///
+/// assert_eq!(u64_to_i64(0), Some(0));
+/// assert_eq!(u64_to_i64(i64::MAX as u64), Some(i64::MAX));
+/// assert_eq!(u64_to_i64(i64::MAX as u64 + 1), None);
+/// assert_eq!(u64_to_i64(u64::MAX), None);
+/// ```
+pub fn u64_to_i64(x: u64) -> Option<i64> {
+    i64::try_from(x).ok()
+}
+
+/// Narrow a 64-bit unsigned integer to 32 bits without truncating
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// * `Some(x)` - If `x <= u32::MAX`
+/// * `None` - If `x` does not fit in a `u32`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::u64_to_u32;
+///
+/// assert_eq!(u64_to_u32(0), Some(0));
+/// assert_eq!(u64_to_u32(u32::MAX as u64), Some(u32::MAX));
+/// assert_eq!(u64_to_u32(u32::MAX as u64 + 1), None);
+/// ```
+pub fn u64_to_u32(x: u64) -> Option<u32> {
+    u32::try_from(x).ok()
+}
+
 /// Maximum of two values
 ///
 /// # Arguments
@@ -85,6 +163,82 @@ pub fn clamp_u64(value: u64, min: u64, max: u64) -> u64 {
     }
 }
 
+/// Clamp a signed value between min and max
+///
+/// Never panics. Like `clamp_u64`, the lower bound is checked first, so if
+/// `min > max` the result is `min` for values below `min` and `max`
+/// otherwise.
+///
+/// # Arguments
+/// * `value` - The value to clamp
+/// * `min` - Minimum bound
+/// * `max` - Maximum bound
+///
+/// # Returns
+/// The clamped value
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::clamp_i64;
+///
+/// assert_eq!(clamp_i64(-50, -10, 10), -10);
+/// assert_eq!(clamp_i64(3, -10, 10), 3);
+/// assert_eq!(clamp_i64(50, -10, 10), 10);
+/// assert_eq!(clamp_i64(i64::MIN, -10, 10), -10);
+///
+/// // Inverted bounds
+/// assert_eq!(clamp_i64(0, 10, -10), 10);
+/// assert_eq!(clamp_i64(20, 10, -10), -10);
+/// ```
+pub fn clamp_i64(value: i64, min: i64, max: i64) -> i64 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// Clamp a floating-point value between min and max
+///
+/// Never panics, unlike `f64::clamp`. The lower bound is checked first, so
+/// if `min > max` the result is `min` for values below `min` and `max`
+/// otherwise. A NaN `value` is returned unchanged.
+///
+/// # Arguments
+/// * `value` - The value to clamp
+/// * `min` - Minimum bound
+/// * `max` - Maximum bound
+///
+/// # Returns
+/// The clamped value
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::clamp_f64;
+///
+/// assert_eq!(clamp_f64(-1.5, 0.0, 1.0), 0.0);
+/// assert_eq!(clamp_f64(0.25, 0.0, 1.0), 0.25);
+/// assert_eq!(clamp_f64(7.0, 0.0, 1.0), 1.0);
+/// assert_eq!(clamp_f64(f64::INFINITY, 0.0, 1.0), 1.0);
+/// assert!(clamp_f64(f64::NAN, 0.0, 1.0).is_nan());
+///
+/// // Inverted bounds
+/// assert_eq!(clamp_f64(0.5, 1.0, 0.0), 1.0);
+/// ```
+pub fn clamp_f64(value: f64, min: f64, max: f64) -> f64 {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
 /// Power function (x^n) with checked overflow
 ///
 /// # Arguments
@@ -98,154 +252,2245 @@ pub fn pow_u64_checked(base: u64, exp: u32) -> Option<u64> {
     base.checked_pow(exp)
 }
 
-/// Integer square root using Newton's method
+/// Addition of unsigned integers with checked overflow
 ///
 /// # Arguments
-/// * `n` - The value
+/// * `a` - First operand
+/// * `b` - Second operand
 ///
 /// # Returns
-/// The integer square root
-pub fn isqrt_u64(n: u64) -> u64 {
-    if n == 0 {
-        return 0;
-    }
-
-    let mut x = n;
-    let mut y = x.div_ceil(2);
+/// * `Some(a + b)` - If the result fits in a `u64`
+/// * `None` - If overflow occurs
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_add_u64;
+///
+/// assert_eq!(checked_add_u64(2, 3), Some(5));
+/// assert_eq!(checked_add_u64(u64::MAX - 1, 1), Some(u64::MAX));
+/// assert_eq!(checked_add_u64(u64::MAX, 1), None);
+/// ```
+pub fn checked_add_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_add(b)
+}
 
-    while y < x {
-        x = y;
-        y = (x + n / x) / 2;
-    }
+/// Subtraction of unsigned integers with checked underflow
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// * `Some(a - b)` - If the result fits in a `u64`
+/// * `None` - If underflow occurs
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_sub_u64;
+///
+/// assert_eq!(checked_sub_u64(5, 3), Some(2));
+/// assert_eq!(checked_sub_u64(3, 3), Some(0));
+/// assert_eq!(checked_sub_u64(3, 5), None);
+/// ```
+pub fn checked_sub_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_sub(b)
+}
 
-    x
+/// Multiplication of unsigned integers with checked overflow
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// * `Some(a * b)` - If the result fits in a `u64`
+/// * `None` - If overflow occurs
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_mul_u64;
+///
+/// assert_eq!(checked_mul_u64(4, 3), Some(12));
+/// assert_eq!(checked_mul_u64(u64::MAX, 1), Some(u64::MAX));
+/// assert_eq!(checked_mul_u64(u64::MAX, 2), None);
+/// assert_eq!(checked_mul_u64(1 << 32, 1 << 32), None);
+/// ```
+pub fn checked_mul_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_mul(b)
 }
 
-/// Greatest common divisor using Euclidean algorithm
+/// Addition of unsigned integers, clamping at the numeric bounds
 ///
 /// # Arguments
-/// * `a` - First value
-/// * `b` - Second value
+/// * `a` - First operand
+/// * `b` - Second operand
 ///
 /// # Returns
-/// The GCD of a and b
-pub fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
-    while b != 0 {
-        let temp = b;
-        b = a % b;
-        a = temp;
-    }
-    a
+/// The result, or `u64::MAX` if it would overflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_add_u64;
+///
+/// assert_eq!(saturating_add_u64(2, 3), 5);
+/// assert_eq!(saturating_add_u64(u64::MAX, 1), u64::MAX);
+/// ```
+pub fn saturating_add_u64(a: u64, b: u64) -> u64 {
+    a.saturating_add(b)
 }
 
-/// Least common multiple
+/// Subtraction of unsigned integers, clamping at the numeric bounds
 ///
 /// # Arguments
-/// * `a` - First value
-/// * `b` - Second value
+/// * `a` - First operand
+/// * `b` - Second operand
 ///
 /// # Returns
-/// The LCM of a and b
-pub fn lcm_u64(a: u64, b: u64) -> Option<u64> {
-    let gcd = gcd_u64(a, b);
-    a.checked_div(gcd).and_then(|q| q.checked_mul(b))
+/// The result, or `0` if it would underflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_sub_u64;
+///
+/// assert_eq!(saturating_sub_u64(5, 3), 2);
+/// assert_eq!(saturating_sub_u64(3, 5), 0);
+/// ```
+pub fn saturating_sub_u64(a: u64, b: u64) -> u64 {
+    a.saturating_sub(b)
 }
 
-/// Check if a number is prime
+/// Multiplication of unsigned integers, clamping at the numeric bounds
 ///
 /// # Arguments
-/// * `n` - The number to check
+/// * `a` - First operand
+/// * `b` - Second operand
 ///
 /// # Returns
-/// true if n is prime, false otherwise
-pub fn is_prime(n: u64) -> bool {
-    if n < 2 {
-        return false;
-    }
-    if n == 2 {
-        return true;
-    }
-    if n.is_multiple_of(2) {
-        return false;
-    }
+/// The result, or `u64::MAX` if it would overflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_mul_u64;
+///
+/// assert_eq!(saturating_mul_u64(4, 3), 12);
+/// assert_eq!(saturating_mul_u64(u64::MAX, 2), u64::MAX);
+/// ```
+pub fn saturating_mul_u64(a: u64, b: u64) -> u64 {
+    a.saturating_mul(b)
+}
 
-    let sqrt_n = isqrt_u64(n);
-    for i in (3..=sqrt_n).step_by(2) {
-        if n.is_multiple_of(i) {
-            return false;
-        }
-    }
+/// Integer division that returns `None` instead of panicking on zero
+///
+/// # Arguments
+/// * `a` - Dividend
+/// * `b` - Divisor
+///
+/// # Returns
+/// * `Some(a / b)` - Quotient rounded towards zero
+/// * `None` - If `b` is zero
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_div_u64;
+///
+/// assert_eq!(checked_div_u64(12, 4), Some(3));
+/// assert_eq!(checked_div_u64(13, 4), Some(3));
+/// assert_eq!(checked_div_u64(13, 0), None);
+/// ```
+pub fn checked_div_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_div(b)
+}
 
-    true
+/// Integer remainder that returns `None` instead of panicking on zero
+///
+/// # Arguments
+/// * `a` - Dividend
+/// * `b` - Divisor
+///
+/// # Returns
+/// * `Some(a % b)` - Remainder
+/// * `None` - If `b` is zero
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_rem_u64;
+///
+/// assert_eq!(checked_rem_u64(12, 4), Some(0));
+/// assert_eq!(checked_rem_u64(13, 4), Some(1));
+/// assert_eq!(checked_rem_u64(13, 0), None);
+/// ```
+pub fn checked_rem_u64(a: u64, b: u64) -> Option<u64> {
+    a.checked_rem(b)
 }
 
-/// Factorial with overflow checking
+/// Integer division rounding up
 ///
 /// # Arguments
-/// * `n` - The value
+/// * `a` - Dividend
+/// * `b` - Divisor
 ///
 /// # Returns
-/// * `Some(result)` - If computation succeeds
-/// * `None` - If overflow occurs
-pub fn factorial_u64(n: u64) -> Option<u64> {
-    let mut result = 1u64;
-    for i in 2..=n {
-        result = result.checked_mul(i)?;
+/// * `Some(ceil(a / b))` - Quotient rounded towards positive infinity
+/// * `None` - If `b` is zero
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::div_ceil_u64;
+///
+/// assert_eq!(div_ceil_u64(12, 4), Some(3));
+/// assert_eq!(div_ceil_u64(13, 4), Some(4));
+/// assert_eq!(div_ceil_u64(0, 4), Some(0));
+/// assert_eq!(div_ceil_u64(u64::MAX, 2), Some(1 << 63));
+/// assert_eq!(div_ceil_u64(13, 0), None);
+/// ```
+pub fn div_ceil_u64(a: u64, b: u64) -> Option<u64> {
+    if b == 0 {
+        None
+    } else {
+        Some(a.div_ceil(b))
     }
-    Some(result)
 }
 
-/// Fibonacci number at position n
+/// Sum a slice of values, detecting overflow
 ///
 /// # Arguments
-/// * `n` - Position in Fibonacci sequence
+/// * `values` - Values to add
 ///
 /// # Returns
-/// The nth Fibonacci number
-pub fn fibonacci_u64(n: u64) -> u64 {
-    if n == 0 {
-        return 0;
-    }
-    if n == 1 {
-        return 1;
-    }
+/// * `Some(sum)` - The total, `0` for an empty slice
+/// * `None` - If the running total would exceed `u64::MAX`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_sum_u64;
+///
+/// assert_eq!(checked_sum_u64(&[10, 20, 30]), Some(60));
+/// assert_eq!(checked_sum_u64(&[]), Some(0));
+/// assert_eq!(checked_sum_u64(&[u64::MAX - 1, 1]), Some(u64::MAX));
+/// assert_eq!(checked_sum_u64(&[u64::MAX, 1]), None);
+/// ```
+pub fn checked_sum_u64(values: &[u64]) -> Option<u64> {
+    values.iter().try_fold(0u64, |acc, &x| acc.checked_add(x))
+}
 
-    let mut a = 0u64;
-    let mut b = 1u64;
+/// Sum a slice of values, clamping at `u64::MAX`
+///
+/// # Arguments
+/// * `values` - Values to add
+///
+/// # Returns
+/// The total, `0` for an empty slice, or `u64::MAX` on overflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_sum_u64;
+///
+/// assert_eq!(saturating_sum_u64(&[10, 20, 30]), 60);
+/// assert_eq!(saturating_sum_u64(&[]), 0);
+/// assert_eq!(saturating_sum_u64(&[u64::MAX, 1, 5]), u64::MAX);
+/// ```
+pub fn saturating_sum_u64(values: &[u64]) -> u64 {
+    values.iter().fold(0u64, |acc, &x| acc.saturating_add(x))
+}
 
-    for _ in 2..=n {
-        let temp = a.wrapping_add(b);
-        a = b;
-        b = temp;
-    }
+/// Addition that reports overflow instead of wrapping
+///
+/// Lets generic code such as [`Vector::checked_sum`] add values of any
+/// integer type without risking a silent wrap in release builds.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::CheckedAdd;
+///
+/// fn add<T: CheckedAdd>(a: T, b: T) -> Option<T> {
+///     CheckedAdd::checked_add(a, b)
+/// }
+///
+/// assert_eq!(add(2u8, 3u8), Some(5));
+/// assert_eq!(add(u8::MAX, 1), None);
+/// assert_eq!(add(i64::MIN, -1), None);
+/// ```
+pub trait CheckedAdd: Sized {
+    /// Add `rhs`, returning `None` on overflow
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+}
 
-    b
+macro_rules! impl_checked_add {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedAdd for $ty {
+                fn checked_add(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_add(self, rhs)
+                }
+            }
+        )*
+    };
 }
 
-/// Modular exponentiation (base^exp mod modulus)
+impl_checked_add!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+/// Addition of signed integers with checked overflow
 ///
 /// # Arguments
-/// * `base` - Base value
-/// * `exp` - Exponent
-/// * `modulus` - Modulus
+/// * `a` - First operand
+/// * `b` - Second operand
 ///
 /// # Returns
-/// (base^exp) mod modulus
-pub fn mod_exp_u64(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
-    if modulus == 1 {
-        return 0;
-    }
+/// * `Some(a + b)` - If the result fits in an `i64`
+/// * `None` - If overflow occurs
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_add_i64;
+///
+/// assert_eq!(checked_add_i64(2, 3), Some(5));
+/// assert_eq!(checked_add_i64(i64::MAX, 1), None);
+/// assert_eq!(checked_add_i64(i64::MIN, -1), None);
+/// ```
+pub fn checked_add_i64(a: i64, b: i64) -> Option<i64> {
+    a.checked_add(b)
+}
 
-    let mut result = 1u64;
-    base %= modulus;
+/// Subtraction of signed integers with checked overflow
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// * `Some(a - b)` - If the result fits in an `i64`
+/// * `None` - If overflow occurs
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_sub_i64;
+///
+/// assert_eq!(checked_sub_i64(5, 7), Some(-2));
+/// assert_eq!(checked_sub_i64(i64::MIN, 1), None);
+/// assert_eq!(checked_sub_i64(i64::MAX, -1), None);
+/// ```
+pub fn checked_sub_i64(a: i64, b: i64) -> Option<i64> {
+    a.checked_sub(b)
+}
 
-    while exp > 0 {
-        if exp % 2 == 1 {
-            result = ((result as u128 * base as u128) % modulus as u128) as u64;
-        }
-        exp >>= 1;
+/// Multiplication of signed integers with checked overflow
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// * `Some(a * b)` - If the result fits in an `i64`
+/// * `None` - If overflow occurs
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::checked_mul_i64;
+///
+/// assert_eq!(checked_mul_i64(-4, 3), Some(-12));
+/// assert_eq!(checked_mul_i64(i64::MAX, 2), None);
+/// assert_eq!(checked_mul_i64(i64::MIN, -1), None);
+/// ```
+pub fn checked_mul_i64(a: i64, b: i64) -> Option<i64> {
+    a.checked_mul(b)
+}
+
+/// Addition of signed integers, clamping at the numeric bounds
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// The result, or `i64::MAX` / `i64::MIN` if it would overflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_add_i64;
+///
+/// assert_eq!(saturating_add_i64(i64::MAX, 1), i64::MAX);
+/// assert_eq!(saturating_add_i64(i64::MIN, -1), i64::MIN);
+/// ```
+pub fn saturating_add_i64(a: i64, b: i64) -> i64 {
+    a.saturating_add(b)
+}
+
+/// Subtraction of signed integers, clamping at the numeric bounds
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// The result, or `i64::MAX` / `i64::MIN` if it would overflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_sub_i64;
+///
+/// assert_eq!(saturating_sub_i64(i64::MIN, 1), i64::MIN);
+/// assert_eq!(saturating_sub_i64(i64::MAX, -1), i64::MAX);
+/// ```
+pub fn saturating_sub_i64(a: i64, b: i64) -> i64 {
+    a.saturating_sub(b)
+}
+
+/// Multiplication of signed integers, clamping at the numeric bounds
+///
+/// # Arguments
+/// * `a` - First operand
+/// * `b` - Second operand
+///
+/// # Returns
+/// The result, or `i64::MAX` / `i64::MIN` if it would overflow
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::saturating_mul_i64;
+///
+/// assert_eq!(saturating_mul_i64(i64::MAX, 2), i64::MAX);
+/// assert_eq!(saturating_mul_i64(i64::MIN, 2), i64::MIN);
+/// ```
+pub fn saturating_mul_i64(a: i64, b: i64) -> i64 {
+    a.saturating_mul(b)
+}
+
+/// Number of set bits
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The number of ones in the binary representation of `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::count_ones;
+///
+/// assert_eq!(count_ones(0), 0);
+/// assert_eq!(count_ones(0b1011), 3);
+/// assert_eq!(count_ones(u64::MAX), 64);
+/// ```
+pub fn count_ones(x: u64) -> u32 {
+    x.count_ones()
+}
+
+/// Number of leading zero bits
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The number of zeros above the most significant set bit (64 for zero)
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::leading_zeros;
+///
+/// assert_eq!(leading_zeros(0), 64);
+/// assert_eq!(leading_zeros(1), 63);
+/// assert_eq!(leading_zeros(u64::MAX), 0);
+/// ```
+pub fn leading_zeros(x: u64) -> u32 {
+    x.leading_zeros()
+}
+
+/// Number of trailing zero bits
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The number of zeros below the least significant set bit (64 for zero)
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::trailing_zeros;
+///
+/// assert_eq!(trailing_zeros(0), 64);
+/// assert_eq!(trailing_zeros(0b1000), 3);
+/// assert_eq!(trailing_zeros(u64::MAX), 0);
+/// ```
+pub fn trailing_zeros(x: u64) -> u32 {
+    x.trailing_zeros()
+}
+
+/// Rotate bits to the left, wrapping the high bits around
+///
+/// # Arguments
+/// * `x` - The value
+/// * `n` - Number of positions (taken modulo 64)
+///
+/// # Returns
+/// The rotated value
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::rotate_left;
+///
+/// assert_eq!(rotate_left(1 << 63, 1), 1);
+/// assert_eq!(rotate_left(0x0123_4567_89ab_cdef, 8), 0x2345_6789_abcd_ef01);
+/// assert_eq!(rotate_left(u64::MAX, 13), u64::MAX);
+/// assert_eq!(rotate_left(0, 5), 0);
+/// ```
+pub fn rotate_left(x: u64, n: u32) -> u64 {
+    x.rotate_left(n)
+}
+
+/// Rotate bits to the right, wrapping the low bits around
+///
+/// # Arguments
+/// * `x` - The value
+/// * `n` - Number of positions (taken modulo 64)
+///
+/// # Returns
+/// The rotated value
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::rotate_right;
+///
+/// assert_eq!(rotate_right(1, 1), 1 << 63);
+/// assert_eq!(rotate_right(0x0123_4567_89ab_cdef, 8), 0xef01_2345_6789_abcd);
+/// assert_eq!(rotate_right(u64::MAX, 13), u64::MAX);
+/// assert_eq!(rotate_right(0, 5), 0);
+/// ```
+pub fn rotate_right(x: u64, n: u32) -> u64 {
+    x.rotate_right(n)
+}
+
+/// Reverse the order of the bits
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// `x` with bit 0 swapped with bit 63, bit 1 with bit 62, and so on
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::reverse_bits;
+///
+/// assert_eq!(reverse_bits(1), 1 << 63);
+/// assert_eq!(reverse_bits(0b1101), 0b1011 << 60);
+/// assert_eq!(reverse_bits(0), 0);
+/// assert_eq!(reverse_bits(u64::MAX), u64::MAX);
+/// ```
+pub fn reverse_bits(x: u64) -> u64 {
+    x.reverse_bits()
+}
+
+/// Check whether a value is a power of two
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// True if exactly one bit of `x` is set (so zero is not a power of two)
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::is_power_of_two;
+///
+/// assert!(!is_power_of_two(0));
+/// assert!(is_power_of_two(1));
+/// assert!(is_power_of_two(1024));
+/// assert!(!is_power_of_two(1023));
+/// assert!(is_power_of_two(1 << 63));
+/// ```
+pub fn is_power_of_two(x: u64) -> bool {
+    x.is_power_of_two()
+}
+
+/// Smallest power of two greater than or equal to a value
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// * `Some(power)` - The next power of two (`1` for zero)
+/// * `None` - If the result would exceed `u64::MAX`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::next_power_of_two;
+///
+/// assert_eq!(next_power_of_two(0), Some(1));
+/// assert_eq!(next_power_of_two(1), Some(1));
+/// assert_eq!(next_power_of_two(64), Some(64));
+/// assert_eq!(next_power_of_two(65), Some(128));
+/// assert_eq!(next_power_of_two(1 << 63), Some(1 << 63));
+/// assert_eq!(next_power_of_two((1 << 63) + 1), None);
+/// assert_eq!(next_power_of_two(u64::MAX), None);
+/// ```
+pub fn next_power_of_two(x: u64) -> Option<u64> {
+    x.checked_next_power_of_two()
+}
+
+/// Floor of the base-2 logarithm
+///
+/// Computed with integer operations only, so it is exact and deterministic.
+///
+/// # Arguments
+/// * `n` - The value
+///
+/// # Returns
+/// * `Some(floor(log2(n)))` - If `n > 0`
+/// * `None` - If `n` is zero
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::ilog2_u64;
+///
+/// assert_eq!(ilog2_u64(1), Some(0));
+/// assert_eq!(ilog2_u64(8), Some(3));
+/// assert_eq!(ilog2_u64(7), Some(2));
+/// assert_eq!(ilog2_u64(u64::MAX), Some(63));
+/// assert_eq!(ilog2_u64(0), None);
+/// ```
+pub fn ilog2_u64(n: u64) -> Option<u64> {
+    n.checked_ilog2().map(u64::from)
+}
+
+/// Floor of the base-10 logarithm
+///
+/// Computed with integer operations only, so it is exact and deterministic.
+/// One less than the number of decimal digits in `n`.
+///
+/// # Arguments
+/// * `n` - The value
+///
+/// # Returns
+/// * `Some(floor(log10(n)))` - If `n > 0`
+/// * `None` - If `n` is zero
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::ilog10_u64;
+///
+/// assert_eq!(ilog10_u64(1), Some(0));
+/// assert_eq!(ilog10_u64(1000), Some(3));
+/// assert_eq!(ilog10_u64(999), Some(2));
+/// assert_eq!(ilog10_u64(u64::MAX), Some(19));
+/// assert_eq!(ilog10_u64(0), None);
+/// ```
+pub fn ilog10_u64(n: u64) -> Option<u64> {
+    n.checked_ilog10().map(u64::from)
+}
+
+/// Floor of the logarithm in an arbitrary base
+///
+/// Computed with integer operations only, so it is exact and deterministic.
+///
+/// # Arguments
+/// * `n` - The value
+/// * `base` - Logarithm base
+///
+/// # Returns
+/// * `Some(floor(log_base(n)))` - If `n > 0` and `base >= 2`
+/// * `None` - Otherwise
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::ilog_u64;
+///
+/// assert_eq!(ilog_u64(81, 3), Some(4));
+/// assert_eq!(ilog_u64(80, 3), Some(3));
+/// assert_eq!(ilog_u64(2, 3), Some(0));
+/// assert_eq!(ilog_u64(0, 3), None);
+/// assert_eq!(ilog_u64(81, 1), None);
+/// assert_eq!(ilog_u64(81, 0), None);
+/// ```
+pub fn ilog_u64(n: u64, base: u64) -> Option<u64> {
+    if base < 2 {
+        return None;
+    }
+    n.checked_ilog(base).map(u64::from)
+}
+
+/// Integer square root using Newton's method
+///
+/// # Arguments
+/// * `n` - The value
+///
+/// # Returns
+/// The integer square root
+pub fn isqrt_u64(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    let mut x = n;
+    let mut y = x.div_ceil(2);
+
+    while y < x {
+        x = y;
+        y = (x + n / x) / 2;
+    }
+
+    x
+}
+
+/// Greatest common divisor using Euclidean algorithm
+///
+/// # Arguments
+/// * `a` - First value
+/// * `b` - Second value
+///
+/// # Returns
+/// The GCD of a and b
+pub fn gcd_u64(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Least common multiple
+///
+/// # Arguments
+/// * `a` - First value
+/// * `b` - Second value
+///
+/// # Returns
+/// The LCM of a and b
+pub fn lcm_u64(a: u64, b: u64) -> Option<u64> {
+    let gcd = gcd_u64(a, b);
+    a.checked_div(gcd).and_then(|q| q.checked_mul(b))
+}
+
+/// Check if a number is prime
+///
+/// # Arguments
+/// * `n` - The number to check
+///
+/// # Returns
+/// true if n is prime, false otherwise
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 {
+        return false;
+    }
+    if n == 2 {
+        return true;
+    }
+    if n.is_multiple_of(2) {
+        return false;
+    }
+
+    let sqrt_n = isqrt_u64(n);
+    for i in (3..=sqrt_n).step_by(2) {
+        if n.is_multiple_of(i) {
+            return false;
+        }
+    }
+
+    true
+}
+
+/// Deterministic Miller-Rabin primality test
+///
+/// Tests against the first twelve primes as witnesses, which is proven exact
+/// for every 64-bit integer. Runs in `O(log^3 n)` time, so unlike
+/// [`is_prime`] it stays fast near `u64::MAX`.
+///
+/// # Arguments
+/// * `n` - The value to check
+///
+/// # Returns
+/// True if n is prime, false otherwise
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{is_prime, is_prime_fast};
+///
+/// for n in 0..2000 {
+///     assert_eq!(is_prime_fast(n), is_prime(n));
+/// }
+///
+/// assert!(is_prime_fast(18_446_744_073_709_551_557)); // largest 64-bit prime
+/// assert!(is_prime_fast(1_000_000_007));
+/// assert!(!is_prime_fast(u64::MAX));
+/// assert!(!is_prime_fast(3_215_031_751)); // strong pseudoprime to bases 2, 3, 5, 7
+/// assert!(!is_prime_fast(4_294_967_297)); // 641 * 6_700_417
+/// ```
+pub fn is_prime_fast(n: u64) -> bool {
+    const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in WITNESSES.iter() {
+        if n == p {
+            return true;
+        }
+        if n.is_multiple_of(p) {
+            return false;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for &a in WITNESSES.iter() {
+        let mut x = mod_exp_u64(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = ((x as u128 * x as u128) % n as u128) as u64;
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+/// All primes up to a bound (sieve of Eratosthenes)
+///
+/// The sieve stores one bit per odd number, so it allocates roughly
+/// `limit / 16` bytes. Keep `limit` small enough for that to be affordable.
+///
+/// # Arguments
+/// * `limit` - Inclusive upper bound
+///
+/// # Returns
+/// Every prime `<= limit`, in ascending order
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::primes_up_to;
+///
+/// assert_eq!(primes_up_to(20).into_inner(), vec![2, 3, 5, 7, 11, 13, 17, 19]);
+/// assert_eq!(primes_up_to(2).into_inner(), vec![2]);
+/// assert!(primes_up_to(1).is_empty());
+/// assert_eq!(primes_up_to(10_000).len(), 1229);
+/// ```
+pub fn primes_up_to(limit: u64) -> Vector<u64> {
+    let mut primes = Vector::new();
+    if limit < 2 {
+        return primes;
+    }
+    primes.push(2);
+
+    // Bit `i` is set once `2 * i + 1` is known to be composite.
+    let bits = limit.div_ceil(2) as usize;
+    let mut composite = vec![0u64; bits.div_ceil(64)];
+    let is_set = |words: &[u64], i: usize| words[i / 64] & (1 << (i % 64)) != 0;
+
+    let sqrt_limit = isqrt_u64(limit);
+    let mut p = 3u64;
+    while p <= sqrt_limit {
+        if !is_set(&composite, (p / 2) as usize) {
+            let mut i = (p * p / 2) as usize;
+            while i < bits {
+                composite[i / 64] |= 1 << (i % 64);
+                i += p as usize;
+            }
+        }
+        p += 2;
+    }
+
+    for i in 1..bits {
+        if !is_set(&composite, i) {
+            primes.push(2 * i as u64 + 1);
+        }
+    }
+
+    primes
+}
+
+/// Prime factorization by trial division
+///
+/// Divides out each candidate up to the square root of the remaining
+/// cofactor; whatever is left above that bound is itself prime. Worst case
+/// (a product of two large primes) takes about `2^31` divisions.
+///
+/// # Arguments
+/// * `n` - The value to factor
+///
+/// # Returns
+/// `(prime, exponent)` pairs in ascending prime order. Both `0` and `1` have
+/// no prime factorization and yield an empty vector.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::factorize;
+///
+/// assert_eq!(factorize(360).into_inner(), vec![(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(97).into_inner(), vec![(97, 1)]);
+/// assert_eq!(factorize(1024).into_inner(), vec![(2, 10)]);
+/// assert_eq!(factorize(2 * 1_000_000_007).into_inner(), vec![(2, 1), (1_000_000_007, 1)]);
+/// assert!(factorize(1).is_empty());
+/// assert!(factorize(0).is_empty());
+/// ```
+pub fn factorize(mut n: u64) -> Vector<(u64, u32)> {
+    let mut factors = Vector::new();
+    if n < 2 {
+        return factors;
+    }
+
+    let mut d = 2u64;
+    while d <= n / d {
+        if n.is_multiple_of(d) {
+            let mut exp = 0u32;
+            while n.is_multiple_of(d) {
+                n /= d;
+                exp += 1;
+            }
+            factors.push((d, exp));
+        }
+        d += if d == 2 { 1 } else { 2 };
+    }
+
+    if n > 1 {
+        factors.push((n, 1));
+    }
+
+    factors
+}
+
+/// Factorial with overflow checking
+///
+/// # Arguments
+/// * `n` - The value
+///
+/// # Returns
+/// * `Some(result)` - If computation succeeds
+/// * `None` - If overflow occurs
+pub fn factorial_u64(n: u64) -> Option<u64> {
+    let mut result = 1u64;
+    for i in 2..=n {
+        result = result.checked_mul(i)?;
+    }
+    Some(result)
+}
+
+/// Fibonacci number at position n
+///
+/// # Arguments
+/// * `n` - Position in Fibonacci sequence
+///
+/// # Returns
+/// The nth Fibonacci number
+pub fn fibonacci_u64(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+    if n == 1 {
+        return 1;
+    }
+
+    let mut a = 0u64;
+    let mut b = 1u64;
+
+    for _ in 2..=n {
+        let temp = a.wrapping_add(b);
+        a = b;
+        b = temp;
+    }
+
+    b
+}
+
+/// Modular exponentiation (base^exp mod modulus)
+///
+/// # Arguments
+/// * `base` - Base value
+/// * `exp` - Exponent
+/// * `modulus` - Modulus
+///
+/// # Returns
+/// (base^exp) mod modulus
+pub fn mod_exp_u64(mut base: u64, mut exp: u64, modulus: u64) -> u64 {
+    if modulus == 1 {
+        return 0;
+    }
+
+    let mut result = 1u64;
+    base %= modulus;
+
+    while exp > 0 {
+        if exp % 2 == 1 {
+            result = ((result as u128 * base as u128) % modulus as u128) as u64;
+        }
+        exp >>= 1;
         base = ((base as u128 * base as u128) % modulus as u128) as u64;
     }
 
-    result
+    result
+}
+
+/// Modular multiplicative inverse using the extended Euclidean algorithm
+///
+/// Intermediate values are kept in `i128`, so the result is exact for all
+/// 64-bit inputs.
+///
+/// # Arguments
+/// * `a` - Value to invert
+/// * `modulus` - Modulus
+///
+/// # Returns
+/// * `Some(inverse)` - The unique `x` in `[0, modulus)` with `(a * x) % modulus == 1 % modulus`
+/// * `None` - If `modulus` is zero or `a` and `modulus` are not coprime
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::mod_inverse_u64;
+///
+/// for (a, m) in [(3u64, 11u64), (10, 17), (7, 1_000_000_007), (u64::MAX, u64::MAX - 1)] {
+///     let inv = mod_inverse_u64(a, m).unwrap();
+///     assert!(inv < m);
+///     assert_eq!((a as u128 * inv as u128) % m as u128, 1);
+/// }
+///
+/// assert_eq!(mod_inverse_u64(3, 11), Some(4));
+/// assert_eq!(mod_inverse_u64(6, 9), None);
+/// assert_eq!(mod_inverse_u64(0, 7), None);
+/// assert_eq!(mod_inverse_u64(5, 0), None);
+/// ```
+pub fn mod_inverse_u64(a: u64, modulus: u64) -> Option<u64> {
+    if modulus == 0 {
+        return None;
+    }
+
+    let m = modulus as i128;
+    let (mut old_r, mut r) = (a as i128 % m, m);
+    let (mut old_s, mut s) = (1i128, 0i128);
+
+    while r != 0 {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, old_s - q * s);
+    }
+
+    if old_r != 1 {
+        return None;
+    }
+
+    Some(old_s.rem_euclid(m) as u64)
+}
+
+/// Solve a pair of simultaneous congruences (Chinese Remainder Theorem)
+///
+/// Finds `x` such that `x ≡ r1 (mod m1)` and `x ≡ r2 (mod m2)`. The moduli
+/// need not be coprime, in which case the residues must agree modulo
+/// their GCD.
+///
+/// # Arguments
+/// * `r1` - First residue
+/// * `m1` - First modulus
+/// * `r2` - Second residue
+/// * `m2` - Second modulus
+///
+/// # Returns
+/// * `Some((x, lcm))` - The smallest non-negative solution and `lcm(m1, m2)`
+/// * `None` - If a modulus is zero, the residues are incompatible, or the
+///   LCM overflows
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::crt_pair;
+///
+/// assert_eq!(crt_pair(2, 3, 3, 5), Some((8, 15)));
+/// assert_eq!(crt_pair(3, 4, 5, 6), Some((11, 12)));
+/// assert_eq!(crt_pair(1, 4, 2, 6), None);
+/// ```
+pub fn crt_pair(r1: u64, m1: u64, r2: u64, m2: u64) -> Option<(u64, u64)> {
+    if m1 == 0 || m2 == 0 {
+        return None;
+    }
+
+    let r1 = r1 % m1;
+    let r2 = r2 % m2;
+    let g = gcd_u64(m1, m2);
+    let lcm = lcm_u64(m1, m2)?;
+
+    let diff = (r2 as u128 + m2 as u128 - (r1 % m2) as u128) % m2 as u128;
+    if !diff.is_multiple_of(g as u128) {
+        return None;
+    }
+
+    let m1_reduced = m1 / g;
+    let m2_reduced = m2 / g;
+    let inverse = mod_inverse_u64(m1_reduced % m2_reduced, m2_reduced)?;
+    let k = (diff / g as u128) * inverse as u128 % m2_reduced as u128;
+    let x = (r1 as u128 + m1 as u128 * k) % lcm as u128;
+
+    Some((x as u64, lcm))
+}
+
+/// Integer k-th root (floor)
+///
+/// # Arguments
+/// * `n` - The value
+/// * `k` - The root degree
+///
+/// # Returns
+/// * `Some(root)` - The largest `r` such that `r^k <= n`
+/// * `None` - If `k` is zero
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::iroot_u64;
+///
+/// assert_eq!(iroot_u64(27, 3), Some(3));
+/// assert_eq!(iroot_u64(26, 3), Some(2));
+/// assert_eq!(iroot_u64(u64::MAX, 2), Some(u32::MAX as u64));
+/// assert_eq!(iroot_u64(5, 0), None);
+/// ```
+pub fn iroot_u64(n: u64, k: u32) -> Option<u64> {
+    if k == 0 {
+        return None;
+    }
+    if k == 1 || n < 2 {
+        return Some(n);
+    }
+
+    let (mut lo, mut hi) = (1u64, n);
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        match mid.checked_pow(k) {
+            Some(p) if p <= n => lo = mid,
+            _ => hi = mid - 1,
+        }
+    }
+
+    Some(lo)
+}
+
+/// Integer k-th root (floor) of a `u128` whose root fits in `u64`
+///
+/// `k` must be non-zero, and for `k == 1` the value must fit in `u64`.
+fn iroot_u128(n: u128, k: u32) -> u64 {
+    if k == 1 || n < 2 {
+        return n as u64;
+    }
+
+    let (mut lo, mut hi) = (1u64, u64::MAX);
+    while lo < hi {
+        let mid = lo + (hi - lo).div_ceil(2);
+        match (mid as u128).checked_pow(k) {
+            Some(p) if p <= n => lo = mid,
+            _ => hi = mid - 1,
+        }
+    }
+
+    lo
+}
+
+/// Greatest common divisor for `u128` intermediates
+fn gcd_u128(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let temp = b;
+        b = a % b;
+        a = temp;
+    }
+    a
+}
+
+/// Arithmetic mean, rounded down
+///
+/// The sum is accumulated in `u128`, so it cannot overflow for any input
+/// that fits in memory.
+///
+/// # Arguments
+/// * `values` - The values to average
+///
+/// # Returns
+/// * `Some(mean)` - The floor of the arithmetic mean
+/// * `None` - If `values` is empty
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::mean_u64;
+///
+/// assert_eq!(mean_u64(&[1, 2, 3, 4]), Some(2));
+/// assert_eq!(mean_u64(&[u64::MAX, u64::MAX, u64::MAX - 2]), Some(u64::MAX - 1));
+/// assert_eq!(mean_u64(&[]), None);
+/// ```
+pub fn mean_u64(values: &[u64]) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    let sum: u128 = values.iter().map(|&v| v as u128).sum();
+    Some((sum / values.len() as u128) as u64)
+}
+
+/// Median value
+///
+/// Sorts `values` in place. For an even number of values the two central
+/// elements are averaged, rounding down.
+///
+/// # Arguments
+/// * `values` - The values (reordered by this call)
+///
+/// # Returns
+/// * `Some(median)` - The median
+/// * `None` - If `values` is empty
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::median_u64;
+///
+/// assert_eq!(median_u64(&mut [5, 1, 3]), Some(3));
+/// assert_eq!(median_u64(&mut [4, 1, 3, 2]), Some(2));
+/// assert_eq!(median_u64(&mut [u64::MAX, u64::MAX - 1]), Some(u64::MAX - 1));
+/// assert_eq!(median_u64(&mut []), None);
+/// ```
+pub fn median_u64(values: &mut [u64]) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+
+    let mid = values.len() / 2;
+    if values.len() % 2 == 1 {
+        Some(values[mid])
+    } else {
+        Some(((values[mid - 1] as u128 + values[mid] as u128) / 2) as u64)
+    }
+}
+
+/// Geometric mean (floor of the n-th root of the product)
+///
+/// The product is accumulated in `u128`, so any two `u64` values (and many
+/// longer inputs) can be averaged exactly.
+///
+/// # Arguments
+/// * `values` - The values to average
+///
+/// # Returns
+/// * `Some(mean)` - The integer geometric mean
+/// * `None` - If `values` is empty or the product overflows `u128`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::geometric_mean_u64;
+///
+/// assert_eq!(geometric_mean_u64(&[1, 4]), Some(2));
+/// assert_eq!(geometric_mean_u64(&[1 << 33, 1 << 33]), Some(1 << 33));
+/// assert_eq!(geometric_mean_u64(&[u64::MAX, u64::MAX]), Some(u64::MAX));
+/// assert_eq!(geometric_mean_u64(&[u64::MAX, u64::MAX, 2]), None);
+/// assert_eq!(geometric_mean_u64(&[]), None);
+/// ```
+pub fn geometric_mean_u64(values: &[u64]) -> Option<u64> {
+    if values.is_empty() {
+        return None;
+    }
+
+    let product = values
+        .iter()
+        .try_fold(1u128, |acc, &v| acc.checked_mul(v as u128))?;
+    let n = u32::try_from(values.len()).ok()?;
+    Some(iroot_u128(product, n))
+}
+
+/// Harmonic mean (floor of `n / sum(1 / x)`)
+///
+/// The reciprocal sum is kept as an exact fraction, so the result is
+/// deterministic.
+///
+/// # Arguments
+/// * `values` - The values to average
+///
+/// # Returns
+/// * `Some(mean)` - The integer harmonic mean
+/// * `None` - If `values` is empty, contains a zero, or the exact
+///   fraction overflows `u128`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::harmonic_mean_u64;
+///
+/// assert_eq!(harmonic_mean_u64(&[1, 4]), Some(1));
+/// assert_eq!(harmonic_mean_u64(&[3, 3, 3]), Some(3));
+/// assert_eq!(harmonic_mean_u64(&[1, 0]), None);
+/// ```
+pub fn harmonic_mean_u64(values: &[u64]) -> Option<u64> {
+    if values.is_empty() || values.contains(&0) {
+        return None;
+    }
+
+    // Reciprocal sum as num / den
+    let (mut num, mut den) = (0u128, 1u128);
+    for &v in values {
+        let v = v as u128;
+        num = num.checked_mul(v)?.checked_add(den)?;
+        den = den.checked_mul(v)?;
+        let g = gcd_u128(num, den);
+        num /= g;
+        den /= g;
+    }
+
+    let mean = (values.len() as u128).checked_mul(den)? / num;
+    u64::try_from(mean).ok()
+}
+
+/// Number of decimal digits in `n!`
+///
+/// Computed as `floor(sum(log10(k)) for k in 2..=n) + 1`, so the factorial
+/// itself is never materialized and `n` can go well past the `u64` range
+/// of `factorial_u64`.
+///
+/// `n` is limited to `10_000` so the `O(n)` loop stays cheap. Within that
+/// range the fractional part of `log10(n!)` never comes closer than
+/// `2.6e-5` to an integer, while the accumulated rounding error of the
+/// summation stays below `1e-10`; every platform with a correctly rounded
+/// or faithful `log10` therefore returns the same, exact digit count.
+///
+/// # Arguments
+/// * `n` - The value
+///
+/// # Returns
+/// * `Some(digits)` - The number of decimal digits of `n!`
+/// * `None` - If `n` is greater than `10_000`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::factorial_digit_count;
+///
+/// assert_eq!(factorial_digit_count(0), Some(1));
+/// assert_eq!(factorial_digit_count(10), Some(7));
+/// assert_eq!(factorial_digit_count(100), Some(158));
+/// // Closest approach to a power of ten within the supported range
+/// assert_eq!(factorial_digit_count(8998), Some(31675));
+/// assert_eq!(factorial_digit_count(10_000), Some(35660));
+/// assert_eq!(factorial_digit_count(10_001), None);
+/// assert_eq!(factorial_digit_count(u64::MAX), None);
+/// ```
+pub fn factorial_digit_count(n: u64) -> Option<u64> {
+    if n > 10_000 {
+        return None;
+    }
+    let log_sum: f64 = (2..=n).map(|k| (k as f64).log10()).sum();
+    Some(log_sum.floor() as u64 + 1)
+}
+
+/// Floating-point power with a floating-point exponent
+///
+/// # Arguments
+/// * `base` - Base value
+/// * `exp` - Exponent
+///
+/// # Returns
+/// `base` raised to `exp`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::powf_f64;
+///
+/// assert_eq!(powf_f64(2.0, 10.0), 1024.0);
+/// assert!((powf_f64(4.0, 0.5) - 2.0).abs() < 1e-12);
+/// ```
+pub fn powf_f64(base: f64, exp: f64) -> f64 {
+    base.powf(exp)
+}
+
+/// Floating-point power with an integer exponent
+///
+/// # Arguments
+/// * `base` - Base value
+/// * `exp` - Exponent
+///
+/// # Returns
+/// `base` raised to `exp`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::powi_f64;
+///
+/// assert_eq!(powi_f64(2.0, 10), 1024.0);
+/// assert_eq!(powi_f64(2.0, -1), 0.5);
+/// ```
+pub fn powi_f64(base: f64, exp: i32) -> f64 {
+    base.powi(exp)
+}
+
+/// Natural exponential function (e^x)
+///
+/// # Arguments
+/// * `x` - Exponent
+///
+/// # Returns
+/// `e` raised to `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{constants, exp_f64};
+///
+/// assert_eq!(exp_f64(0.0), 1.0);
+/// assert!((exp_f64(1.0) - constants::E).abs() < 1e-12);
+/// ```
+pub fn exp_f64(x: f64) -> f64 {
+    x.exp()
+}
+
+/// Floating-point square root
+///
+/// Correctly rounded (IEEE 754 requires it), but still a float; use
+/// `isqrt_u64` for exact integer results in consensus logic.
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// * `Some(sqrt(x))` - If `x >= 0`
+/// * `None` - If `x` is negative or NaN
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::sqrt_f64;
+///
+/// assert_eq!(sqrt_f64(16.0), Some(4.0));
+/// assert_eq!(sqrt_f64(0.0), Some(0.0));
+/// assert_eq!(sqrt_f64(-4.0), None);
+/// assert_eq!(sqrt_f64(f64::NAN), None);
+/// ```
+pub fn sqrt_f64(x: f64) -> Option<f64> {
+    if x >= 0.0 {
+        Some(x.sqrt())
+    } else {
+        None
+    }
+}
+
+/// Floating-point cube root
+///
+/// Defined for negative inputs, unlike `sqrt_f64`.
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The real cube root of `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::cbrt_f64;
+///
+/// assert!((cbrt_f64(27.0) - 3.0).abs() < 1e-12);
+/// assert!((cbrt_f64(-27.0) + 3.0).abs() < 1e-12);
+/// assert_eq!(cbrt_f64(0.0), 0.0);
+/// ```
+pub fn cbrt_f64(x: f64) -> f64 {
+    x.cbrt()
+}
+
+/// Round down to the nearest integer
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The largest integer less than or equal to `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::floor_f64;
+///
+/// assert_eq!(floor_f64(2.7), 2.0);
+/// assert_eq!(floor_f64(-2.5), -3.0);
+/// assert_eq!(floor_f64(-2.0), -2.0);
+/// ```
+pub fn floor_f64(x: f64) -> f64 {
+    x.floor()
+}
+
+/// Round up to the nearest integer
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The smallest integer greater than or equal to `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::ceil_f64;
+///
+/// assert_eq!(ceil_f64(2.1), 3.0);
+/// assert_eq!(ceil_f64(-2.5), -2.0);
+/// assert_eq!(ceil_f64(2.0), 2.0);
+/// ```
+pub fn ceil_f64(x: f64) -> f64 {
+    x.ceil()
+}
+
+/// Round to the nearest integer, with halves rounded away from zero
+///
+/// `2.5` rounds to `3.0` and `-2.5` to `-3.0`; this is not banker's
+/// rounding.
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The nearest integer to `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::round_f64;
+///
+/// assert_eq!(round_f64(2.4), 2.0);
+/// assert_eq!(round_f64(2.5), 3.0);
+/// assert_eq!(round_f64(3.5), 4.0);
+/// assert_eq!(round_f64(-2.5), -3.0);
+/// assert_eq!(round_f64(-2.4), -2.0);
+/// ```
+pub fn round_f64(x: f64) -> f64 {
+    x.round()
+}
+
+/// Round towards zero, dropping the fractional part
+///
+/// # Arguments
+/// * `x` - The value
+///
+/// # Returns
+/// The integer part of `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::trunc_f64;
+///
+/// assert_eq!(trunc_f64(2.7), 2.0);
+/// assert_eq!(trunc_f64(-2.7), -2.0);
+/// ```
+pub fn trunc_f64(x: f64) -> f64 {
+    x.trunc()
+}
+
+/// Round to a number of decimal places, with halves rounded away from zero
+///
+/// Scales by `10^decimals`, rounds like `round_f64`, and scales back.
+/// Decimal fractions are rarely exact in binary, so a value that looks like
+/// a half (such as `1.005`) may be stored slightly below it and round down.
+/// If scaling would overflow, `value` is returned unchanged. Use `Fixed64`
+/// when exact decimal behavior matters.
+///
+/// # Arguments
+/// * `value` - The value
+/// * `decimals` - Number of decimal places to keep
+///
+/// # Returns
+/// `value` rounded to `decimals` places
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::round_to;
+///
+/// assert_eq!(round_to(3.14159, 2), 3.14);
+/// assert_eq!(round_to(-1.23756, 2), -1.24);
+/// assert_eq!(round_to(0.125, 2), 0.13);
+/// assert_eq!(round_to(2.5, 0), 3.0);
+/// assert_eq!(round_to(1e300, 10), 1e300);
+/// ```
+pub fn round_to(value: f64, decimals: u32) -> f64 {
+    let factor = 10f64.powi(decimals.min(i32::MAX as u32) as i32);
+    let scaled = value * factor;
+    if !scaled.is_finite() {
+        return value;
+    }
+    scaled.round() / factor
+}
+
+/// Sine of an angle in radians
+///
+/// Defined for every finite input; infinities and NaN yield NaN. Accuracy is
+/// that of the platform libm, typically within 1 ulp.
+///
+/// # Arguments
+/// * `x` - Angle in radians
+///
+/// # Returns
+/// The sine of `x`, in `[-1, 1]`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{constants, sin};
+///
+/// // Tolerance covers libm rounding differences between platforms.
+/// const EPSILON: f64 = 1e-12;
+///
+/// assert_eq!(sin(0.0), 0.0);
+/// assert!((sin(constants::PI / 2.0) - 1.0).abs() < EPSILON);
+/// assert!(sin(constants::PI).abs() < EPSILON);
+/// assert!(sin(f64::INFINITY).is_nan());
+/// ```
+pub fn sin(x: f64) -> f64 {
+    x.sin()
+}
+
+/// Cosine of an angle in radians
+///
+/// Defined for every finite input; infinities and NaN yield NaN.
+///
+/// # Arguments
+/// * `x` - Angle in radians
+///
+/// # Returns
+/// The cosine of `x`, in `[-1, 1]`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{constants, cos};
+///
+/// const EPSILON: f64 = 1e-12;
+///
+/// assert_eq!(cos(0.0), 1.0);
+/// assert!((cos(constants::PI) + 1.0).abs() < EPSILON);
+/// assert!(cos(constants::PI / 2.0).abs() < EPSILON);
+/// ```
+pub fn cos(x: f64) -> f64 {
+    x.cos()
+}
+
+/// Tangent of an angle in radians
+///
+/// Defined for every finite input. Near odd multiples of `PI / 2` the result
+/// grows very large rather than becoming infinite, because those angles are
+/// not exactly representable.
+///
+/// # Arguments
+/// * `x` - Angle in radians
+///
+/// # Returns
+/// The tangent of `x`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{constants, tan};
+///
+/// const EPSILON: f64 = 1e-12;
+///
+/// assert_eq!(tan(0.0), 0.0);
+/// assert!((tan(constants::PI / 4.0) - 1.0).abs() < EPSILON);
+/// ```
+pub fn tan(x: f64) -> f64 {
+    x.tan()
+}
+
+/// Four-quadrant arctangent of `y / x`
+///
+/// Uses the signs of both arguments to pick the quadrant, so it is defined
+/// even when `x` is zero.
+///
+/// # Arguments
+/// * `y` - Y coordinate
+/// * `x` - X coordinate
+///
+/// # Returns
+/// The angle in radians, in `[-PI, PI]`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{atan2, constants};
+///
+/// const EPSILON: f64 = 1e-12;
+///
+/// assert_eq!(atan2(0.0, 1.0), 0.0);
+/// assert!((atan2(1.0, 0.0) - constants::PI / 2.0).abs() < EPSILON);
+/// assert!((atan2(1.0, -1.0) - 3.0 * constants::PI / 4.0).abs() < EPSILON);
+/// ```
+pub fn atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+/// Natural logarithm
+///
+/// # Arguments
+/// * `x` - Input value
+///
+/// # Returns
+/// * `Some(ln(x))` - If `x > 0`
+/// * `None` - If `x` is zero, negative or NaN
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::{constants, ln};
+///
+/// assert_eq!(ln(1.0), Some(0.0));
+/// assert!((ln(constants::E).unwrap() - 1.0).abs() < 1e-12);
+/// assert_eq!(ln(0.0), None);
+/// assert_eq!(ln(-1.0), None);
+/// ```
+pub fn ln(x: f64) -> Option<f64> {
+    if x > 0.0 {
+        Some(x.ln())
+    } else {
+        None
+    }
+}
+
+/// Base-2 logarithm
+///
+/// # Arguments
+/// * `x` - Input value
+///
+/// # Returns
+/// * `Some(log2(x))` - If `x > 0`
+/// * `None` - If `x` is zero, negative or NaN
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::log2;
+///
+/// assert_eq!(log2(8.0), Some(3.0));
+/// assert_eq!(log2(0.5), Some(-1.0));
+/// assert_eq!(log2(0.0), None);
+/// assert_eq!(log2(-8.0), None);
+/// ```
+pub fn log2(x: f64) -> Option<f64> {
+    if x > 0.0 {
+        Some(x.log2())
+    } else {
+        None
+    }
+}
+
+/// Base-10 logarithm
+///
+/// # Arguments
+/// * `x` - Input value
+///
+/// # Returns
+/// * `Some(log10(x))` - If `x > 0`
+/// * `None` - If `x` is zero, negative or NaN
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::log10;
+///
+/// assert_eq!(log10(1000.0), Some(3.0));
+/// assert_eq!(log10(0.0), None);
+/// assert_eq!(log10(-10.0), None);
+/// ```
+pub fn log10(x: f64) -> Option<f64> {
+    if x > 0.0 {
+        Some(x.log10())
+    } else {
+        None
+    }
+}
+
+/// Logarithm in an arbitrary base
+///
+/// Computed as `ln(x) / ln(base)`, so the result may differ from the exact
+/// value in the last few bits.
+///
+/// # Arguments
+/// * `x` - Input value
+/// * `base` - Logarithm base
+///
+/// # Returns
+/// * `Some(log_base(x))` - If `x > 0`, `base > 0` and `base != 1`
+/// * `None` - Otherwise
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::log;
+///
+/// assert!((log(81.0, 3.0).unwrap() - 4.0).abs() < 1e-12);
+/// assert!((log(0.25, 2.0).unwrap() + 2.0).abs() < 1e-12);
+/// assert_eq!(log(0.0, 3.0), None);
+/// assert_eq!(log(-9.0, 3.0), None);
+/// assert_eq!(log(9.0, 1.0), None);
+/// assert_eq!(log(9.0, -3.0), None);
+/// ```
+pub fn log(x: f64, base: f64) -> Option<f64> {
+    if x > 0.0 && base > 0.0 && base != 1.0 {
+        Some(x.log(base))
+    } else {
+        None
+    }
+}
+
+/// Length of the half-open range `[start, end)`
+///
+/// Shared by the vector and string range methods so that inverted ranges
+/// are reported consistently.
+///
+/// # Arguments
+/// * `start` - Start index (inclusive)
+/// * `end` - End index (exclusive)
+///
+/// # Returns
+/// * `Ok(len)` - `end - start`
+/// * `Err(String)` - If `start > end`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::range_len;
+///
+/// assert_eq!(range_len(2, 5), Ok(3));
+/// assert_eq!(range_len(4, 4), Ok(0));
+/// assert!(range_len(5, 2).is_err());
+/// ```
+pub fn range_len(start: u64, end: u64) -> Result<u64, String> {
+    end.checked_sub(start)
+        .ok_or_else(|| format!("Invalid range: start {} > end {}", start, end))
+}
+
+/// Deterministic pseudo-random number generator
+///
+/// A SplitMix64 generator seeded with a `u64`. The same seed always yields
+/// the same sequence on every platform, so validators replaying a
+/// transaction observe identical values. It is not cryptographically
+/// secure: anyone who knows the seed can predict every output.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::Rng;
+///
+/// let mut a = Rng::new(7);
+/// let mut b = Rng::new(7);
+/// assert_eq!(a.next_u64(), b.next_u64());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Create a generator from a seed
+    ///
+    /// Every seed, including zero, produces a full-period sequence.
+    ///
+    /// # Arguments
+    /// * `seed` - Initial state
+    ///
+    /// # Returns
+    /// A new generator
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    /// Generate the next 64-bit value
+    ///
+    /// # Returns
+    /// A uniformly distributed `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Rng;
+    ///
+    /// let mut rng = Rng::new(42);
+    /// assert_eq!(rng.next_u64(), 0xbdd7_3226_2feb_6e95);
+    /// assert_eq!(rng.next_u64(), 0x28ef_e333_b266_f103);
+    /// assert_eq!(rng.next_u64(), 0x4752_6757_130f_9f52);
+    /// ```
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Generate a value in the half-open range `[low, high)`
+    ///
+    /// Draws that would bias the result towards small values are rejected
+    /// and redrawn, so every value in the range is equally likely.
+    ///
+    /// # Arguments
+    /// * `low` - Lower bound (inclusive)
+    /// * `high` - Upper bound (exclusive)
+    ///
+    /// # Returns
+    /// A uniformly distributed value in `[low, high)`, or `low` if the range
+    /// is empty (`high <= low`)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Rng;
+    ///
+    /// let mut rng = Rng::new(42);
+    /// for _ in 0..1000 {
+    ///     let x = rng.next_range(10, 20);
+    ///     assert!((10..20).contains(&x));
+    /// }
+    ///
+    /// assert_eq!(rng.next_range(5, 6), 5);
+    /// assert_eq!(rng.next_range(5, 5), 5);
+    /// assert_eq!(rng.next_range(9, 3), 9);
+    /// ```
+    pub fn next_range(&mut self, low: u64, high: u64) -> u64 {
+        if high <= low {
+            return low;
+        }
+
+        let span = high - low;
+        // Smallest draw that keeps `u64::MAX + 1 - threshold` a multiple of `span`.
+        let threshold = span.wrapping_neg() % span;
+        loop {
+            let x = self.next_u64();
+            if x >= threshold {
+                return low + x % span;
+            }
+        }
+    }
+
+    /// Generate a random boolean
+    ///
+    /// # Returns
+    /// `true` or `false` with equal probability
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Rng;
+    ///
+    /// let mut rng = Rng::new(42);
+    /// assert!(rng.next_bool());
+    /// assert!(!rng.next_bool());
+    /// ```
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() >> 63 == 1
+    }
+
+    /// Pick an index with probability proportional to its weight
+    ///
+    /// Weights are summed as `u128`, so any number of `u64::MAX` weights can
+    /// be combined without overflow. Entries with weight zero are never
+    /// chosen. The same seed and weights always yield the same index.
+    ///
+    /// # Arguments
+    /// * `weights` - Relative weight of each index
+    ///
+    /// # Returns
+    /// * `Some(index)` - The chosen index into `weights`
+    /// * `None` - If `weights` is empty or all weights are zero
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Rng;
+    ///
+    /// let mut rng = Rng::new(42);
+    /// let picks: Vec<u64> = (0..8)
+    ///     .map(|_| rng.weighted_index(&[1, 0, 3, 6]).unwrap())
+    ///     .collect();
+    /// assert_eq!(picks, [2, 2, 3, 3, 0, 2, 3, 3]);
+    ///
+    /// for _ in 0..1000 {
+    ///     let i = rng.weighted_index(&[0, 5, 0, u64::MAX, 0]).unwrap();
+    ///     assert!(i == 1 || i == 3);
+    /// }
+    ///
+    /// assert_eq!(rng.weighted_index(&[]), None);
+    /// assert_eq!(rng.weighted_index(&[0, 0]), None);
+    /// assert_eq!(rng.weighted_index(&[0, 7, 0]), Some(1));
+    /// ```
+    pub fn weighted_index(&mut self, weights: &[u64]) -> Option<u64> {
+        let total: u128 = weights.iter().map(|&w| w as u128).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let target = self.next_below_u128(total);
+        let mut cumulative = 0u128;
+        for (i, &w) in weights.iter().enumerate() {
+            cumulative += w as u128;
+            if target < cumulative {
+                return Some(i as u64);
+            }
+        }
+        unreachable!("target is below the total weight")
+    }
+
+    /// Generate a uniform value in `[0, bound)` for a non-zero `bound`
+    fn next_below_u128(&mut self, bound: u128) -> u128 {
+        if bound <= u64::MAX as u128 {
+            return self.next_range(0, bound as u64) as u128;
+        }
+
+        let threshold = bound.wrapping_neg() % bound;
+        loop {
+            let x = ((self.next_u64() as u128) << 64) | self.next_u64() as u128;
+            if x >= threshold {
+                return x % bound;
+            }
+        }
+    }
+}
+
+/// Unsigned fixed-point decimal with nine fractional digits
+///
+/// Stores `value * 10^9` in a `u64`, so it represents values from `0` to
+/// about `18_446_744_073.709551615` in steps of `10^-9`. All arithmetic is
+/// integer-only and therefore deterministic across platforms, which makes
+/// it suitable for token math where `f64` is not.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::Fixed64;
+///
+/// let price = Fixed64::from_raw(1_500_000_000); // 1.5
+/// let qty = Fixed64::from_integer(4).unwrap();
+/// assert_eq!(price.checked_mul(qty), Fixed64::from_integer(6));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Fixed64 {
+    raw: u64,
+}
+
+impl Fixed64 {
+    /// Number of raw units per whole unit (10^9)
+    pub const SCALE: u64 = 1_000_000_000;
+
+    /// Create a value from its raw scaled representation
+    ///
+    /// # Arguments
+    /// * `raw` - The value multiplied by [`Fixed64::SCALE`]
+    ///
+    /// # Returns
+    /// A new fixed-point value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// let quarter = Fixed64::from_raw(250_000_000);
+    /// assert_eq!(quarter.to_f64(), 0.25);
+    /// ```
+    pub fn from_raw(raw: u64) -> Self {
+        Fixed64 { raw }
+    }
+
+    /// Get the raw scaled representation
+    ///
+    /// # Returns
+    /// The value multiplied by [`Fixed64::SCALE`]
+    pub fn raw(&self) -> u64 {
+        self.raw
+    }
+
+    /// Create a value from a whole number
+    ///
+    /// # Arguments
+    /// * `n` - The whole number
+    ///
+    /// # Returns
+    /// * `Some(value)` - If `n` is representable
+    /// * `None` - If `n * SCALE` overflows a `u64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// assert_eq!(Fixed64::from_integer(3).unwrap().raw(), 3_000_000_000);
+    /// assert_eq!(Fixed64::from_integer(u64::MAX), None);
+    /// ```
+    pub fn from_integer(n: u64) -> Option<Self> {
+        n.checked_mul(Self::SCALE).map(Self::from_raw)
+    }
+
+    /// Add two values with checked overflow
+    ///
+    /// # Arguments
+    /// * `other` - Value to add
+    ///
+    /// # Returns
+    /// * `Some(self + other)` - If the sum is representable
+    /// * `None` - If overflow occurs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// let a = Fixed64::from_raw(100_000_000); // 0.1
+    /// let b = Fixed64::from_raw(200_000_000); // 0.2
+    /// assert_eq!(a.checked_add(b), Some(Fixed64::from_raw(300_000_000)));
+    /// assert_eq!(Fixed64::from_raw(u64::MAX).checked_add(a), None);
+    /// ```
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.raw.checked_add(other.raw).map(Self::from_raw)
+    }
+
+    /// Subtract two values with checked underflow
+    ///
+    /// # Arguments
+    /// * `other` - Value to subtract
+    ///
+    /// # Returns
+    /// * `Some(self - other)` - If `other <= self`
+    /// * `None` - If the result would be negative
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// let a = Fixed64::from_raw(300_000_000);
+    /// let b = Fixed64::from_raw(100_000_000);
+    /// assert_eq!(a.checked_sub(b), Some(Fixed64::from_raw(200_000_000)));
+    /// assert_eq!(b.checked_sub(a), None);
+    /// ```
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.raw.checked_sub(other.raw).map(Self::from_raw)
+    }
+
+    /// Multiply two values with checked overflow
+    ///
+    /// The product is computed in `u128` and rescaled, truncating any digits
+    /// beyond the ninth decimal place.
+    ///
+    /// # Arguments
+    /// * `other` - Value to multiply by
+    ///
+    /// # Returns
+    /// * `Some(self * other)` - If the product is representable
+    /// * `None` - If overflow occurs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// let half = Fixed64::from_raw(500_000_000);
+    /// let ten = Fixed64::from_integer(10).unwrap();
+    /// assert_eq!(half.checked_mul(ten), Fixed64::from_integer(5));
+    /// assert_eq!(half.checked_mul(half), Some(Fixed64::from_raw(250_000_000)));
+    ///
+    /// // 0.000000001 * 0.5 truncates to zero
+    /// let tiny = Fixed64::from_raw(1);
+    /// assert_eq!(tiny.checked_mul(half), Some(Fixed64::from_raw(0)));
+    ///
+    /// let big = Fixed64::from_integer(10_000_000_000).unwrap();
+    /// assert_eq!(big.checked_mul(ten), None);
+    /// ```
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        let product = self.raw as u128 * other.raw as u128 / Self::SCALE as u128;
+        u64::try_from(product).ok().map(Self::from_raw)
+    }
+
+    /// Divide two values with checked overflow and division by zero
+    ///
+    /// The dividend is rescaled in `u128` before dividing, and the quotient
+    /// is truncated after the ninth decimal place.
+    ///
+    /// # Arguments
+    /// * `other` - Divisor
+    ///
+    /// # Returns
+    /// * `Some(self / other)` - If the quotient is representable
+    /// * `None` - If `other` is zero or overflow occurs
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// let one = Fixed64::from_integer(1).unwrap();
+    /// let three = Fixed64::from_integer(3).unwrap();
+    /// let four = Fixed64::from_integer(4).unwrap();
+    ///
+    /// assert_eq!(one.checked_div(four), Some(Fixed64::from_raw(250_000_000)));
+    /// assert_eq!(one.checked_div(three), Some(Fixed64::from_raw(333_333_333)));
+    /// assert_eq!(one.checked_div(Fixed64::from_raw(0)), None);
+    /// assert_eq!(Fixed64::from_raw(u64::MAX).checked_div(Fixed64::from_raw(1)), None);
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.raw == 0 {
+            return None;
+        }
+        let quotient = self.raw as u128 * Self::SCALE as u128 / other.raw as u128;
+        u64::try_from(quotient).ok().map(Self::from_raw)
+    }
+
+    /// Convert to a floating-point number
+    ///
+    /// The conversion may lose precision and is intended for display only;
+    /// never feed the result back into contract arithmetic.
+    ///
+    /// # Returns
+    /// The approximate value as an `f64`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Fixed64;
+    ///
+    /// assert_eq!(Fixed64::from_raw(1_250_000_000).to_f64(), 1.25);
+    /// ```
+    pub fn to_f64(&self) -> f64 {
+        self.raw as f64 / Self::SCALE as f64
+    }
+}
+
+impl CheckedAdd for Fixed64 {
+    fn checked_add(self, rhs: Self) -> Option<Self> {
+        Fixed64::checked_add(self, rhs)
+    }
 }
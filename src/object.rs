@@ -11,9 +11,71 @@ use silver_core::ObjectID;
 use std::fmt;
 
 /// Object reference for accessing objects in storage
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// References are ordered by `id`, then `version`, then `digest`, so a
+/// sorted collection groups all versions of an object together in
+/// ascending order and can be binary-searched.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::ObjectRef;
+/// use silver_core::ObjectID;
+/// use std::cmp::Ordering;
+///
+/// let a = ObjectID::new([1u8; 64]);
+/// let b = ObjectID::new([2u8; 64]);
+///
+/// let mut refs = vec![
+///     ObjectRef::new(b, 1, [0u8; 32]),
+///     ObjectRef::new(a, 2, [0u8; 32]),
+///     ObjectRef::new(a, 1, [9u8; 32]),
+///     ObjectRef::new(a, 1, [3u8; 32]),
+/// ];
+/// refs.sort();
+///
+/// assert_eq!(
+///     refs,
+///     vec![
+///         ObjectRef::new(a, 1, [3u8; 32]),
+///         ObjectRef::new(a, 1, [9u8; 32]),
+///         ObjectRef::new(a, 2, [0u8; 32]),
+///         ObjectRef::new(b, 1, [0u8; 32]),
+///     ]
+/// );
+/// assert_eq!(refs.binary_search(&ObjectRef::new(a, 2, [0u8; 32])), Ok(2));
+/// assert_eq!(refs[0].cmp(&ObjectRef::new(a, 1, [3u8; 32])), Ordering::Equal);
+/// ```
+///
+/// With the `borsh` feature:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::ObjectRef;
+/// use silver_core::ObjectID;
+///
+/// let obj_ref = ObjectRef::new(ObjectID::new([7u8; 64]), 3, [9u8; 32]);
+/// let bytes = borsh::to_vec(&obj_ref).unwrap();
+/// assert_eq!(bytes.len(), 64 + 8 + 32);
+/// assert_eq!(borsh::from_slice::<ObjectRef>(&bytes).unwrap(), obj_ref);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct ObjectRef {
+    // Field order defines the derived ordering; keep `id`, `version`, `digest`.
     /// The object ID
+    #[cfg_attr(
+        feature = "borsh",
+        borsh(
+            serialize_with = "borsh_ids::serialize_object_id",
+            deserialize_with = "borsh_ids::deserialize_object_id"
+        )
+    )]
     pub id: ObjectID,
     /// The version of the object
     pub version: u64,
@@ -62,6 +124,31 @@ impl ObjectRef {
     pub fn digest(&self) -> &[u8; 32] {
         &self.digest
     }
+
+    /// Check that the digest matches the given object bytes
+    ///
+    /// Recomputes the digest with [`compute_digest`] and compares it with
+    /// the stored one, detecting stale or tampered object data.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Serialized object contents
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{compute_digest, ObjectRef};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let obj_ref = ObjectRef::new(id, 1, compute_digest(b"balance=100"));
+    /// assert!(obj_ref.verify(b"balance=100"));
+    /// assert!(!obj_ref.verify(b"balance=101"));
+    /// assert!(!obj_ref.verify(b""));
+    /// ```
+    pub fn verify(&self, bytes: &[u8]) -> bool {
+        compute_digest(bytes) == self.digest
+    }
 }
 
 impl fmt::Display for ObjectRef {
@@ -70,18 +157,222 @@ impl fmt::Display for ObjectRef {
     }
 }
 
+/// Compute the 32-byte BLAKE3 digest of object bytes
+///
+/// This is the digest format stored in [`ObjectRef::digest`].
+///
+/// # Arguments
+///
+/// * `bytes` - Serialized object contents
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::compute_digest;
+///
+/// let digest = compute_digest(b"");
+/// assert_eq!(digest[..4], [0xaf, 0x13, 0x49, 0xb9]);
+/// assert_eq!(compute_digest(b"abc"), compute_digest(b"abc"));
+/// assert_ne!(compute_digest(b"abc"), compute_digest(b"abd"));
+/// ```
+pub fn compute_digest(bytes: &[u8]) -> [u8; 32] {
+    *blake3::hash(bytes).as_bytes()
+}
+
 /// Ownership information for an object
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// `Owner` is `Clone` but not `Copy`: the [`Owner::Multisig`] variant holds
+/// a list of signers. Code that relied on copying an owner must clone it.
+///
+/// With the `borsh` feature:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::Owner;
+/// use silver_core::SilverAddress;
+///
+/// let signers = vec![SilverAddress([1u8; 64]), SilverAddress([2u8; 64])];
+/// for owner in [
+///     Owner::Address(SilverAddress([1u8; 64])),
+///     Owner::Shared,
+///     Owner::Immutable,
+///     Owner::multisig(signers, 2).unwrap(),
+/// ] {
+///     let bytes = borsh::to_vec(&owner).unwrap();
+///     assert_eq!(borsh::from_slice::<Owner>(&bytes).unwrap(), owner);
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub enum Owner {
     /// Object is owned by an address
-    Address(silver_core::SilverAddress),
+    Address(
+        #[cfg_attr(
+            feature = "borsh",
+            borsh(
+                serialize_with = "borsh_ids::serialize_address",
+                deserialize_with = "borsh_ids::deserialize_address"
+            )
+        )]
+        silver_core::SilverAddress,
+    ),
     /// Object is shared (no single owner)
     Shared,
     /// Object is immutable (frozen)
     Immutable,
+    /// Object is owned jointly by several addresses
+    ///
+    /// Built with [`Owner::multisig`], which rejects an unsatisfiable
+    /// threshold and duplicate signers.
+    Multisig(MultisigPolicy),
+}
+
+/// Signers and threshold of a multisig owner
+///
+/// The fields are private so that every policy has distinct signers and
+/// satisfies `1 <= threshold <= signers.len()`. Policies are only created by
+/// [`Owner::multisig`], and deserialization applies the same checks.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::Owner;
+/// use silver_core::SilverAddress;
+///
+/// let signers = vec![SilverAddress([1u8; 64]), SilverAddress([2u8; 64])];
+/// if let Owner::Multisig(policy) = Owner::multisig(signers.clone(), 2).unwrap() {
+///     assert_eq!(policy.threshold(), 2);
+///     assert_eq!(policy.signers(), &signers[..]);
+/// }
+/// ```
+///
+/// With the `borsh` feature, decoding an invalid policy fails:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::Owner;
+///
+/// // Variant 3 (`Multisig`), zero signers, threshold 1
+/// let bytes = [3u8, 0, 0, 0, 0, 1, 0];
+/// assert!(borsh::from_slice::<Owner>(&bytes).is_err());
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct MultisigPolicy {
+    signers: Vec<silver_core::SilverAddress>,
+    threshold: u16,
+}
+
+impl MultisigPolicy {
+    /// Validate and build a policy
+    fn new(signers: Vec<silver_core::SilverAddress>, threshold: u16) -> Result<Self, String> {
+        if threshold == 0 {
+            return Err("Multisig threshold must be at least 1".to_string());
+        }
+        if threshold as usize > signers.len() {
+            return Err(format!(
+                "Multisig threshold {} exceeds signer count {}",
+                threshold,
+                signers.len()
+            ));
+        }
+        let mut seen = std::collections::HashSet::with_capacity(signers.len());
+        if let Some(dup) = signers.iter().find(|addr| !seen.insert(*addr)) {
+            return Err(format!("Duplicate multisig signer: {}", dup));
+        }
+        Ok(MultisigPolicy { signers, threshold })
+    }
+
+    /// Get the addresses allowed to sign
+    pub fn signers(&self) -> &[silver_core::SilverAddress] {
+        &self.signers
+    }
+
+    /// Get the number of signatures required
+    pub fn threshold(&self) -> u16 {
+        self.threshold
+    }
+}
+
+impl<'de> Deserialize<'de> for MultisigPolicy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Fields {
+            signers: Vec<silver_core::SilverAddress>,
+            threshold: u16,
+        }
+
+        let fields = Fields::deserialize(deserializer)?;
+        MultisigPolicy::new(fields.signers, fields.threshold).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshSerialize for MultisigPolicy {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh_ids::serialize_addresses(&self.signers, writer)?;
+        borsh::BorshSerialize::serialize(&self.threshold, writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl borsh::BorshDeserialize for MultisigPolicy {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let signers = borsh_ids::deserialize_addresses(reader)?;
+        let threshold = u16::deserialize_reader(reader)?;
+        MultisigPolicy::new(signers, threshold)
+            .map_err(|e| borsh::io::Error::new(borsh::io::ErrorKind::InvalidData, e))
+    }
 }
 
 impl Owner {
+    /// Create a multisig owner
+    ///
+    /// # Arguments
+    ///
+    /// * `signers` - Addresses allowed to sign
+    /// * `threshold` - Number of signatures required
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Owner::Multisig)` - If the signers are distinct and
+    ///   `1 <= threshold <= signers.len()`
+    /// * `Err(String)` - If the threshold is zero or exceeds the signer count,
+    ///   or an address appears more than once in `signers`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Owner;
+    /// use silver_core::SilverAddress;
+    ///
+    /// let signers = vec![SilverAddress([1u8; 64]), SilverAddress([2u8; 64])];
+    /// let owner = Owner::multisig(signers.clone(), 2).unwrap();
+    /// assert_eq!(owner.to_string(), "Multisig(2 of 2)");
+    /// assert!(Owner::multisig(signers.clone(), 0).is_err());
+    /// assert!(Owner::multisig(signers, 3).is_err());
+    ///
+    /// // One key cannot satisfy a 2-of-2 policy on its own
+    /// let a = SilverAddress([1u8; 64]);
+    /// assert!(Owner::multisig(vec![a, a], 2).is_err());
+    /// assert!(Owner::multisig(vec![a, a], 1).is_err());
+    /// ```
+    pub fn multisig(
+        signers: Vec<silver_core::SilverAddress>,
+        threshold: u16,
+    ) -> Result<Self, String> {
+        MultisigPolicy::new(signers, threshold).map(Owner::Multisig)
+    }
+
     /// Check if the owner is an address
     ///
     /// # Examples
@@ -126,6 +417,63 @@ impl Owner {
         matches!(self, Owner::Immutable)
     }
 
+    /// Check if the object has multisig ownership
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Owner;
+    /// use silver_core::SilverAddress;
+    ///
+    /// let owner = Owner::multisig(vec![SilverAddress([1u8; 64])], 1).unwrap();
+    /// assert!(owner.is_multisig());
+    /// assert!(!owner.is_address());
+    /// assert!(!Owner::Shared.is_multisig());
+    /// ```
+    pub fn is_multisig(&self) -> bool {
+        matches!(self, Owner::Multisig(_))
+    }
+
+    /// Get the signature threshold if this is a multisig owner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Owner;
+    /// use silver_core::SilverAddress;
+    ///
+    /// let signers = vec![SilverAddress([1u8; 64]), SilverAddress([2u8; 64])];
+    /// let owner = Owner::multisig(signers, 2).unwrap();
+    /// assert_eq!(owner.threshold(), Some(2));
+    /// assert_eq!(Owner::Immutable.threshold(), None);
+    /// ```
+    pub fn threshold(&self) -> Option<u16> {
+        match self {
+            Owner::Multisig(policy) => Some(policy.threshold()),
+            _ => None,
+        }
+    }
+
+    /// Get the signers if this is a multisig owner
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Owner;
+    /// use silver_core::SilverAddress;
+    ///
+    /// let signers = vec![SilverAddress([1u8; 64]), SilverAddress([2u8; 64])];
+    /// let owner = Owner::multisig(signers.clone(), 1).unwrap();
+    /// assert_eq!(owner.signers(), Some(&signers[..]));
+    /// assert_eq!(Owner::Shared.signers(), None);
+    /// ```
+    pub fn signers(&self) -> Option<&[silver_core::SilverAddress]> {
+        match self {
+            Owner::Multisig(policy) => Some(policy.signers()),
+            _ => None,
+        }
+    }
+
     /// Get the address if this is an address owner
     ///
     /// # Examples
@@ -152,20 +500,63 @@ impl fmt::Display for Owner {
             Owner::Address(addr) => write!(f, "Address({})", addr),
             Owner::Shared => write!(f, "Shared"),
             Owner::Immutable => write!(f, "Immutable"),
+            Owner::Multisig(policy) => write!(
+                f,
+                "Multisig({} of {})",
+                policy.threshold,
+                policy.signers.len()
+            ),
         }
     }
 }
 
 /// Object metadata
+///
+/// With the `borsh` feature, custom entries are encoded in key order, so
+/// equal metadata always produces identical bytes:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::{ObjectMetadata, Owner};
+/// use silver_core::ObjectID;
+///
+/// let id = ObjectID::new([0u8; 64]);
+/// let mut a = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+/// a.set_custom("type", b"coin");
+/// a.set_custom("name", b"silver");
+/// let mut b = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+/// b.set_custom("name", b"silver");
+/// b.set_custom("type", b"coin");
+///
+/// let bytes = borsh::to_vec(&a).unwrap();
+/// assert_eq!(bytes, borsh::to_vec(&b).unwrap());
+/// assert_eq!(borsh::from_slice::<ObjectMetadata>(&bytes).unwrap(), a);
+/// # }
+/// ```
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct ObjectMetadata {
     /// Object ID
+    #[cfg_attr(
+        feature = "borsh",
+        borsh(
+            serialize_with = "borsh_ids::serialize_object_id",
+            deserialize_with = "borsh_ids::deserialize_object_id"
+        )
+    )]
     pub id: ObjectID,
     /// Object owner
     pub owner: Owner,
     /// Object version
     pub version: u64,
     /// Object size in bytes
+    ///
+    /// Covers the object contents only; custom metadata is accounted
+    /// separately by [`ObjectMetadata::custom_bytes`].
     pub size: u64,
     /// Creation timestamp
     pub created_at: u64,
@@ -215,7 +606,7 @@ impl ObjectMetadata {
 
     /// Get the object owner
     pub fn owner(&self) -> Owner {
-        self.owner
+        self.owner.clone()
     }
 
     /// Set the object owner
@@ -237,7 +628,64 @@ impl ObjectMetadata {
         self.version += 1;
     }
 
+    /// Apply an update only if the version is unchanged
+    ///
+    /// A compare-and-swap on the version: when `expected_version` matches,
+    /// sets the size, records `timestamp` as the modification time and
+    /// increments the version. Otherwise nothing is changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `expected_version` - Version the caller last observed
+    /// * `new_size` - New object size
+    /// * `timestamp` - Modification timestamp
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the update was applied
+    /// * `Err(String)` - If the current version differs from `expected_version`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    ///
+    /// assert!(metadata.try_update(1, 2048, 1500).is_ok());
+    /// assert_eq!(metadata.version(), 2);
+    /// assert_eq!(metadata.size(), 2048);
+    /// assert_eq!(metadata.modified_at(), 1500);
+    ///
+    /// // A writer that still holds version 1 is rejected
+    /// assert!(metadata.try_update(1, 4096, 1600).is_err());
+    /// assert_eq!(metadata.version(), 2);
+    /// assert_eq!(metadata.size(), 2048);
+    /// assert_eq!(metadata.modified_at(), 1500);
+    /// ```
+    pub fn try_update(
+        &mut self,
+        expected_version: u64,
+        new_size: u64,
+        timestamp: u64,
+    ) -> Result<(), String> {
+        if self.version != expected_version {
+            return Err(format!(
+                "Version mismatch: expected {}, found {}",
+                expected_version, self.version
+            ));
+        }
+        self.size = new_size;
+        self.modified_at = timestamp;
+        self.version += 1;
+        Ok(())
+    }
+
     /// Get the object size
+    ///
+    /// Excludes custom metadata, see [`ObjectMetadata::custom_bytes`].
     pub fn size(&self) -> u64 {
         self.size
     }
@@ -307,6 +755,111 @@ impl ObjectMetadata {
         self.custom.get(key).cloned()
     }
 
+    /// Set custom metadata to an integer, encoded as 8 big-endian bytes
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Metadata key
+    /// * `value` - Metadata value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// metadata.set_custom_u64("supply", 258);
+    /// assert_eq!(metadata.get_custom("supply"), Some(vec![0, 0, 0, 0, 0, 0, 1, 2]));
+    /// ```
+    pub fn set_custom_u64(&mut self, key: &str, value: u64) {
+        self.set_custom(key, &value.to_be_bytes());
+    }
+
+    /// Get custom metadata as an integer
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Metadata key
+    ///
+    /// # Returns
+    ///
+    /// * `Some(value)` - If the key holds exactly 8 bytes
+    /// * `None` - If the key doesn't exist or has a different length
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// metadata.set_custom_u64("supply", u64::MAX);
+    /// metadata.set_custom("type", b"coin");
+    ///
+    /// assert_eq!(metadata.get_custom_u64("supply"), Some(u64::MAX));
+    /// assert_eq!(metadata.get_custom_u64("type"), None);
+    /// assert_eq!(metadata.get_custom_u64("missing"), None);
+    /// ```
+    pub fn get_custom_u64(&self, key: &str) -> Option<u64> {
+        let bytes: [u8; 8] = self.custom.get(key)?.as_slice().try_into().ok()?;
+        Some(u64::from_be_bytes(bytes))
+    }
+
+    /// Set custom metadata to a string, encoded as UTF-8
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Metadata key
+    /// * `value` - Metadata value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// metadata.set_custom_str("name", "Silver");
+    /// assert_eq!(metadata.get_custom("name"), Some(b"Silver".to_vec()));
+    /// ```
+    pub fn set_custom_str(&mut self, key: &str, value: &str) {
+        self.set_custom(key, value.as_bytes());
+    }
+
+    /// Get custom metadata as a string
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Metadata key
+    ///
+    /// # Returns
+    ///
+    /// * `Some(value)` - If the key holds valid UTF-8
+    /// * `None` - If the key doesn't exist or isn't valid UTF-8
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// metadata.set_custom_str("name", "Ünïcode");
+    /// metadata.set_custom("raw", &[0xff, 0xfe]);
+    ///
+    /// assert_eq!(metadata.get_custom_str("name"), Some("Ünïcode".to_string()));
+    /// assert_eq!(metadata.get_custom_str("raw"), None);
+    /// assert_eq!(metadata.get_custom_str("missing"), None);
+    /// ```
+    pub fn get_custom_str(&self, key: &str) -> Option<String> {
+        String::from_utf8(self.custom.get(key)?.clone()).ok()
+    }
+
     /// Remove custom metadata
     ///
     /// # Arguments
@@ -343,6 +896,163 @@ impl ObjectMetadata {
         self.custom.keys().cloned().collect()
     }
 
+    /// Get the storage cost of the custom metadata
+    ///
+    /// Custom entries are not included in `size`, so the total metered
+    /// footprint of an object is `size() + custom_bytes()`.
+    ///
+    /// # Returns
+    ///
+    /// Sum of the UTF-8 key lengths and value lengths of all entries
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// assert_eq!(metadata.custom_bytes(), 0);
+    ///
+    /// metadata.set_custom("type", b"coin");
+    /// metadata.set_custom("név", b"x");
+    /// assert_eq!(metadata.custom_bytes(), 4 + 4 + 4 + 1);
+    ///
+    /// metadata.set_custom("type", b"nft");
+    /// assert_eq!(metadata.custom_bytes(), 4 + 3 + 4 + 1);
+    ///
+    /// metadata.remove_custom("type");
+    /// assert_eq!(metadata.custom_bytes(), 4 + 1);
+    /// assert_eq!(metadata.size(), 1024);
+    /// ```
+    pub fn custom_bytes(&self) -> u64 {
+        self.custom
+            .iter()
+            .map(|(key, value)| (key.len() + value.len()) as u64)
+            .sum()
+    }
+
+    /// Get all custom metadata entries whose key starts with a prefix
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Key prefix to match
+    ///
+    /// # Returns
+    ///
+    /// Matching `(key, value)` pairs, sorted by key
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// metadata.set_custom("attr:color", b"red");
+    /// metadata.set_custom("attr:size", b"xl");
+    /// metadata.set_custom("type", b"coin");
+    ///
+    /// let attrs = metadata.custom_with_prefix("attr:");
+    /// assert_eq!(
+    ///     attrs,
+    ///     vec![
+    ///         ("attr:color".to_string(), b"red".to_vec()),
+    ///         ("attr:size".to_string(), b"xl".to_vec()),
+    ///     ]
+    /// );
+    /// ```
+    pub fn custom_with_prefix(&self, prefix: &str) -> Vec<(String, Vec<u8>)> {
+        let mut entries: Vec<(String, Vec<u8>)> = self
+            .custom
+            .iter()
+            .filter(|(key, _)| key.starts_with(prefix))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+        entries.sort();
+        entries
+    }
+
+    /// Remove all custom metadata entries whose key starts with a prefix
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Key prefix to match
+    ///
+    /// # Returns
+    ///
+    /// Number of entries removed
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut metadata = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// metadata.set_custom("attr:color", b"red");
+    /// metadata.set_custom("attr:size", b"xl");
+    /// metadata.set_custom("type", b"coin");
+    ///
+    /// assert_eq!(metadata.remove_custom_prefix("attr:"), 2);
+    /// assert_eq!(metadata.custom_keys(), vec!["type".to_string()]);
+    /// ```
+    pub fn remove_custom_prefix(&mut self, prefix: &str) -> u64 {
+        let before = self.custom.len();
+        self.custom.retain(|key, _| !key.starts_with(prefix));
+        (before - self.custom.len()) as u64
+    }
+
+    /// Copy custom metadata entries from another object
+    ///
+    /// Keys missing from `self` are always copied. Keys present in both are
+    /// replaced only when `overwrite` is true. Non-custom fields are left
+    /// unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Metadata to copy entries from
+    /// * `overwrite` - Whether `other` wins on conflicting keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{ObjectMetadata, Owner};
+    /// use silver_core::ObjectID;
+    ///
+    /// let id = ObjectID::new([0u8; 64]);
+    /// let mut defaults = ObjectMetadata::new(id, Owner::Shared, 1024, 1000);
+    /// defaults.set_custom("color", b"red");
+    /// defaults.set_custom("size", b"m");
+    ///
+    /// let mut overrides = ObjectMetadata::new(id, Owner::Immutable, 64, 5);
+    /// overrides.set_custom("color", b"blue");
+    /// overrides.set_custom("shape", b"round");
+    ///
+    /// let mut kept = defaults.clone();
+    /// kept.merge_custom(&overrides, false);
+    /// assert_eq!(kept.get_custom("color"), Some(b"red".to_vec()));
+    /// assert_eq!(kept.get_custom("shape"), Some(b"round".to_vec()));
+    /// assert_eq!(kept.get_custom("size"), Some(b"m".to_vec()));
+    ///
+    /// defaults.merge_custom(&overrides, true);
+    /// assert_eq!(defaults.get_custom("color"), Some(b"blue".to_vec()));
+    /// assert_eq!(defaults.get_custom("shape"), Some(b"round".to_vec()));
+    /// assert_eq!(defaults.get_custom("size"), Some(b"m".to_vec()));
+    /// assert_eq!(defaults.size(), 1024);
+    /// assert!(defaults.owner().is_shared());
+    /// ```
+    pub fn merge_custom(&mut self, other: &ObjectMetadata, overwrite: bool) {
+        for (key, value) in &other.custom {
+            if overwrite || !self.custom.contains_key(key) {
+                self.custom.insert(key.clone(), value.clone());
+            }
+        }
+    }
+
     /// Clear all custom metadata
     pub fn clear_custom(&mut self) {
         self.custom.clear();
@@ -401,3 +1111,54 @@ impl fmt::Display for ObjectMetadata {
         )
     }
 }
+
+/// Borsh encoding for the `silver_core` ID wrappers
+///
+/// `silver-core` does not implement Borsh, so ID fields are encoded here as
+/// their raw 64 bytes, and address lists as a `u32` count followed by each
+/// address.
+#[cfg(feature = "borsh")]
+mod borsh_ids {
+    use borsh::io::{Error, ErrorKind, Read, Result, Write};
+    use borsh::{BorshDeserialize, BorshSerialize};
+    use silver_core::{ObjectID, SilverAddress};
+
+    pub(super) fn serialize_object_id<W: Write>(id: &ObjectID, writer: &mut W) -> Result<()> {
+        id.0.serialize(writer)
+    }
+
+    pub(super) fn deserialize_object_id<R: Read>(reader: &mut R) -> Result<ObjectID> {
+        <[u8; 64]>::deserialize_reader(reader).map(ObjectID::new)
+    }
+
+    pub(super) fn serialize_address<W: Write>(addr: &SilverAddress, writer: &mut W) -> Result<()> {
+        addr.0.serialize(writer)
+    }
+
+    pub(super) fn deserialize_address<R: Read>(reader: &mut R) -> Result<SilverAddress> {
+        <[u8; 64]>::deserialize_reader(reader).map(SilverAddress)
+    }
+
+    pub(super) fn serialize_addresses<W: Write>(
+        addrs: &[SilverAddress],
+        writer: &mut W,
+    ) -> Result<()> {
+        let len = u32::try_from(addrs.len())
+            .map_err(|_| Error::new(ErrorKind::InvalidData, "Too many addresses"))?;
+        len.serialize(writer)?;
+        for addr in addrs {
+            serialize_address(addr, writer)?;
+        }
+        Ok(())
+    }
+
+    pub(super) fn deserialize_addresses<R: Read>(reader: &mut R) -> Result<Vec<SilverAddress>> {
+        let len = u32::deserialize_reader(reader)?;
+        // Grow as addresses arrive rather than trusting the length prefix.
+        let mut addrs = Vec::new();
+        for _ in 0..len {
+            addrs.push(deserialize_address(reader)?);
+        }
+        Ok(addrs)
+    }
+}
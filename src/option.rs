@@ -12,6 +12,26 @@ use std::fmt;
 /// Option type for representing optional values
 ///
 /// An Option is either Some(value) or None.
+///
+/// With the `borsh` feature, options use Borsh's standard `Option` layout:
+/// a `0` byte for `None`, or a `1` byte followed by the value:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::Option;
+///
+/// let some: Option<u16> = Option::Some(7);
+/// let bytes = borsh::to_vec(&some).unwrap();
+/// assert_eq!(bytes, [1, 7, 0]);
+/// assert_eq!(borsh::from_slice::<Option<u16>>(&bytes).unwrap(), some);
+///
+/// let none: Option<u16> = Option::None;
+/// let bytes = borsh::to_vec(&none).unwrap();
+/// assert_eq!(bytes, [0]);
+/// assert_eq!(borsh::from_slice::<Option<u16>>(&bytes).unwrap(), none);
+/// # }
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Option<T> {
     /// Some value
@@ -347,6 +367,707 @@ impl<T> Option<T> {
             Option::None => other,
         }
     }
+
+    /// Combine two options, resolving the case where both are Some
+    ///
+    /// Returns `Some(f(a, b))` if both are Some, whichever value is present
+    /// if only one is, and None if neither is.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another option
+    /// * `f` - Function combining two present values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let both = Option::Some(3u64).merge(Option::Some(4u64), |a, b| a + b);
+    /// assert_eq!(both, Option::Some(7));
+    ///
+    /// let one = Option::None.merge(Option::Some(4u64), |a, b| a + b);
+    /// assert_eq!(one, Option::Some(4));
+    ///
+    /// let neither: Option<u64> = Option::None.merge(Option::None, |a, b| a + b);
+    /// assert_eq!(neither, Option::None);
+    /// ```
+    pub fn merge<F>(self, other: Option<T>, f: F) -> Option<T>
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match (self, other) {
+            (Option::Some(a), Option::Some(b)) => Option::Some(f(a, b)),
+            (Option::Some(a), Option::None) => Option::Some(a),
+            (Option::None, other) => other,
+        }
+    }
+
+    /// Take the value out of the option, leaving None in its place
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, String};
+    ///
+    /// let mut x = Option::Some(String::from_str("owner"));
+    /// assert_eq!(x.take(), Option::Some(String::from_str("owner")));
+    /// assert!(x.is_none());
+    /// assert_eq!(x.take(), Option::None);
+    /// ```
+    pub fn take(&mut self) -> Option<T> {
+        std::mem::replace(self, Option::None)
+    }
+
+    /// Replace the value in the option, returning the previous contents
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - New value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, String};
+    ///
+    /// let mut x = Option::Some(String::from_str("old"));
+    /// assert_eq!(x.replace(String::from_str("new")), Option::Some(String::from_str("old")));
+    /// assert_eq!(x, Option::Some(String::from_str("new")));
+    ///
+    /// let mut y: Option<u64> = Option::None;
+    /// assert_eq!(y.replace(7), Option::None);
+    /// assert_eq!(y, Option::Some(7));
+    /// ```
+    pub fn replace(&mut self, value: T) -> Option<T> {
+        std::mem::replace(self, Option::Some(value))
+    }
+
+    /// Insert a value if None, then return a mutable reference to the value
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to insert if the option is None
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let mut x: Option<u64> = Option::None;
+    /// *x.get_or_insert(5) += 1;
+    /// assert_eq!(x, Option::Some(6));
+    ///
+    /// let mut y = Option::Some(42u64);
+    /// assert_eq!(*y.get_or_insert(5), 42);
+    /// ```
+    pub fn get_or_insert(&mut self, value: T) -> &mut T {
+        self.get_or_insert_with(|| value)
+    }
+
+    /// Insert a computed value if None, then return a mutable reference to the value
+    ///
+    /// The function is only called if the option is None.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to compute the value to insert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let mut cache: Option<Vector<u64>> = Option::None;
+    /// cache.get_or_insert_with(Vector::new).push(1);
+    /// cache.get_or_insert_with(|| panic!("already initialized")).push(2);
+    /// assert_eq!(cache, Option::Some(Vector::from_vec(vec![1, 2])));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if let Option::None = self {
+            *self = Option::Some(f());
+        }
+
+        match self {
+            Option::Some(v) => v,
+            Option::None => unreachable!("option was just populated"),
+        }
+    }
+
+    /// Extract the value from Some by consuming the option, panicking if None
+    ///
+    /// Unlike `unwrap`, this does not require `T: Copy`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the option is None
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x = Option::Some(Vector::from_vec(vec![1u64, 2u64]));
+    /// assert_eq!(x.into_unwrap().len(), 2);
+    /// ```
+    pub fn into_unwrap(self) -> T {
+        match self {
+            Option::Some(v) => v,
+            Option::None => panic!("Called unwrap on None"),
+        }
+    }
+
+    /// Extract the value from Some by consuming the option, or return a default
+    ///
+    /// Unlike `unwrap_or`, this does not require `T: Copy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - Default value if None
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x: Option<Vector<u64>> = Option::None;
+    /// assert!(x.into_unwrap_or(Vector::new()).is_empty());
+    /// ```
+    pub fn into_unwrap_or(self, default: T) -> T {
+        match self {
+            Option::Some(v) => v,
+            Option::None => default,
+        }
+    }
+
+    /// Apply a function to the value by consuming the option
+    ///
+    /// Unlike `map`, this does not require `T: Copy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x = Option::Some(Vector::from_vec(vec![1u64, 2u64, 3u64]));
+    /// let total = x
+    ///     .into_map(|v| v.into_inner())
+    ///     .into_and_then(|v| if v.is_empty() { Option::None } else { Option::Some(v) })
+    ///     .into_filter(|v| v.len() > 2)
+    ///     .into_map(|v| v.iter().sum::<u64>());
+    /// assert_eq!(total, Option::Some(6));
+    /// ```
+    pub fn into_map<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Option::Some(v) => Option::Some(f(v)),
+            Option::None => Option::None,
+        }
+    }
+
+    /// Apply a function that returns an Option by consuming the option
+    ///
+    /// Unlike `and_then`, this does not require `T: Copy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x: Option<Vector<u64>> = Option::Some(Vector::new());
+    /// let first = x.into_and_then(|v| match v.get(0) {
+    ///     Some(&n) => Option::Some(n),
+    ///     None => Option::None,
+    /// });
+    /// assert_eq!(first, Option::None);
+    /// ```
+    pub fn into_and_then<U, F>(self, f: F) -> Option<U>
+    where
+        F: FnOnce(T) -> Option<U>,
+    {
+        match self {
+            Option::Some(v) => f(v),
+            Option::None => Option::None,
+        }
+    }
+
+    /// Filter the value based on a predicate by consuming the option
+    ///
+    /// Unlike `filter`, this does not require `T: Copy`.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Predicate function
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, String};
+    ///
+    /// let x = Option::Some(String::from_str("hello"));
+    /// assert!(x.clone().into_filter(|s| s.len() == 5).is_some());
+    /// assert!(x.into_filter(|s| s.is_empty()).is_none());
+    /// ```
+    pub fn into_filter<F>(self, predicate: F) -> Option<T>
+    where
+        F: FnOnce(&T) -> bool,
+    {
+        match self {
+            Option::Some(v) if predicate(&v) => Option::Some(v),
+            _ => Option::None,
+        }
+    }
+
+    /// Combine two options into an option of a pair
+    ///
+    /// Returns `Some((a, b))` only if both options are Some.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another option
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let some_a: Option<u64> = Option::Some(1);
+    /// let some_b: Option<&str> = Option::Some("b");
+    /// let none_a: Option<u64> = Option::None;
+    /// let none_b: Option<&str> = Option::None;
+    ///
+    /// assert_eq!(some_a.zip(some_b), Option::Some((1, "b")));
+    /// assert_eq!(some_a.zip(none_b), Option::None);
+    /// assert_eq!(none_a.zip(some_b), Option::None);
+    /// assert_eq!(none_a.zip(none_b), Option::None);
+    /// ```
+    pub fn zip<U>(self, other: Option<U>) -> Option<(T, U)> {
+        match (self, other) {
+            (Option::Some(a), Option::Some(b)) => Option::Some((a, b)),
+            _ => Option::None,
+        }
+    }
+
+    /// Convert to a Result, mapping None to the given error
+    ///
+    /// # Arguments
+    ///
+    /// * `err` - Error to return if None
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let x = Option::Some(42u64);
+    /// assert_eq!(x.ok_or("missing field".to_string()), Ok(42));
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// assert_eq!(y.ok_or("missing field".to_string()), Err("missing field".to_string()));
+    /// ```
+    pub fn ok_or<E>(self, err: E) -> Result<T, E> {
+        match self {
+            Option::Some(v) => Ok(v),
+            Option::None => Err(err),
+        }
+    }
+
+    /// Convert to a Result, computing the error lazily if None
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to compute the error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let x = Option::Some(42u64);
+    /// assert_eq!(x.ok_or_else(|| format!("missing {}", "amount")), Ok(42));
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// assert_eq!(y.ok_or_else(|| format!("missing {}", "amount")), Err("missing amount".to_string()));
+    /// ```
+    pub fn ok_or_else<E, F>(self, f: F) -> Result<T, E>
+    where
+        F: FnOnce() -> E,
+    {
+        match self {
+            Option::Some(v) => Ok(v),
+            Option::None => Err(f()),
+        }
+    }
+
+    /// Convert into the standard library option type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let x = Option::Some(42u64);
+    /// assert_eq!(x.into_std(), Some(42));
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// assert_eq!(y.into_std(), None);
+    ///
+    /// let round_trip: Option<u64> = Option::from(Some(7u64).map(|v| v + 1));
+    /// assert_eq!(round_trip, Option::Some(8));
+    /// assert_eq!(Option::<u64>::from(None), Option::None);
+    /// ```
+    pub fn into_std(self) -> std::option::Option<T> {
+        match self {
+            Option::Some(v) => Some(v),
+            Option::None => None,
+        }
+    }
+
+    /// Return Some if exactly one of the two options is Some
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Another option
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let some_a: Option<u64> = Option::Some(1);
+    /// let some_b: Option<u64> = Option::Some(2);
+    /// let none: Option<u64> = Option::None;
+    ///
+    /// assert_eq!(some_a.xor(none), Option::Some(1));
+    /// assert_eq!(none.xor(some_b), Option::Some(2));
+    /// assert_eq!(some_a.xor(some_b), Option::None);
+    /// assert_eq!(none.xor(none), Option::None);
+    /// ```
+    pub fn xor(self, other: Option<T>) -> Option<T> {
+        match (self, other) {
+            (Option::Some(a), Option::None) => Option::Some(a),
+            (Option::None, Option::Some(b)) => Option::Some(b),
+            _ => Option::None,
+        }
+    }
+
+    /// Check if the option contains the given value
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to compare against
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let x: Option<u64> = Option::Some(42);
+    /// assert!(x.contains(&42));
+    /// assert!(!x.contains(&7));
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// assert!(!y.contains(&42));
+    /// ```
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        match self {
+            Option::Some(v) => v == value,
+            Option::None => false,
+        }
+    }
+
+    /// Get an iterator over the contained value, if any
+    ///
+    /// Yields one item for Some and none for None. The option also
+    /// implements `IntoIterator` for owning iteration.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x: Option<u64> = Option::Some(42);
+    /// assert_eq!(x.iter().collect::<Vec<_>>(), vec![&42]);
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// assert_eq!(y.iter().count(), 0);
+    ///
+    /// let chained: Vec<u64> = x.into_iter().chain(y).chain(Option::Some(7)).collect();
+    /// assert_eq!(chained, vec![42, 7]);
+    ///
+    /// let entries = Vector::from_vec(vec![Option::Some(1u64), Option::None, Option::Some(3u64)]);
+    /// let present: Vec<u64> = entries.iter().flat_map(|e| e.iter().copied()).collect();
+    /// assert_eq!(present, vec![1, 3]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.as_ref().into_iter()
+    }
+
+    /// Extract the value from Some by consuming the option, or return the default
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x: Option<u64> = Option::Some(42);
+    /// assert_eq!(x.unwrap_or_default(), 42);
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// assert_eq!(y.unwrap_or_default(), 0);
+    ///
+    /// let z: Option<Vector<u64>> = Option::None;
+    /// assert!(z.unwrap_or_default().is_empty());
+    /// ```
+    pub fn unwrap_or_default(self) -> T
+    where
+        T: Default,
+    {
+        match self {
+            Option::Some(v) => v,
+            Option::None => T::default(),
+        }
+    }
+
+    /// Extract the value from Some, panicking with a custom message if None
+    ///
+    /// # Arguments
+    ///
+    /// * `msg` - Panic message describing the violated invariant
+    ///
+    /// # Panics
+    ///
+    /// Panics with `msg` if the option is None
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let x: Option<u64> = Option::Some(42);
+    /// assert_eq!(x.expect("balance must be set"), 42);
+    ///
+    /// let y: Option<u64> = Option::None;
+    /// let err = std::panic::catch_unwind(|| y.expect("balance must be set")).unwrap_err();
+    /// assert_eq!(err.downcast_ref::<String>().unwrap(), "balance must be set");
+    /// ```
+    pub fn expect(self, msg: &str) -> T {
+        match self {
+            Option::Some(v) => v,
+            Option::None => panic!("{}", msg),
+        }
+    }
+
+    /// Call a function on the contained value, if any, and return the option
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to call with a reference to the value
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let mut calls = 0;
+    /// let x: Option<u64> = Option::Some(42);
+    /// let y = x.inspect(|v| {
+    ///     calls += 1;
+    ///     assert_eq!(*v, 42);
+    /// });
+    /// assert_eq!(y, Option::Some(42));
+    /// assert_eq!(calls, 1);
+    ///
+    /// let z: Option<u64> = Option::None;
+    /// assert_eq!(z.inspect(|_| calls += 1), Option::None);
+    /// assert_eq!(calls, 1);
+    /// ```
+    pub fn inspect<F>(self, f: F) -> Self
+    where
+        F: FnOnce(&T),
+    {
+        if let Option::Some(v) = &self {
+            f(v);
+        }
+        self
+    }
+
+    /// Map the value with a function, or compute a default, by consuming the option
+    ///
+    /// Only one of the two closures is called: `default` on None, `f` on Some.
+    /// Unlike `map_or`, the default is lazy and `T: Copy` is not required.
+    ///
+    /// # Arguments
+    ///
+    /// * `default` - Function to compute the result if None
+    /// * `f` - Function to apply to the value if Some
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, String};
+    ///
+    /// let name = Option::Some(String::from_str("alice"));
+    /// let len = name.map_or_else(|| panic!("default must not run"), |s| s.len());
+    /// assert_eq!(len, 5);
+    ///
+    /// let missing: Option<String> = Option::None;
+    /// let len = missing.map_or_else(|| 0, |_| panic!("f must not run"));
+    /// assert_eq!(len, 0);
+    /// ```
+    pub fn map_or_else<U, D, F>(self, default: D, f: F) -> U
+    where
+        D: FnOnce() -> U,
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Option::Some(v) => f(v),
+            Option::None => default(),
+        }
+    }
+
+    /// Apply a function to a reference to the value if Some
+    ///
+    /// Unlike `map`, this borrows the value instead of copying it, so it
+    /// works for non-`Copy` payloads without cloning.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, String};
+    ///
+    /// let name = Option::Some(String::from_str("alice"));
+    /// assert_eq!(name.map_ref(|s| s.len()), Option::Some(5));
+    /// assert!(name.is_some()); // still usable
+    ///
+    /// let missing: Option<String> = Option::None;
+    /// assert_eq!(missing.map_ref(|s| s.len()), Option::None);
+    /// ```
+    pub fn map_ref<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnOnce(&T) -> U,
+    {
+        match self {
+            Option::Some(v) => Option::Some(f(v)),
+            Option::None => Option::None,
+        }
+    }
+
+    /// Apply a function that returns an Option to a reference to the value
+    ///
+    /// Unlike `and_then`, this borrows the value instead of copying it, so
+    /// it works for non-`Copy` payloads without cloning.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to apply
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let x = Option::Some(Vector::from_vec(vec![7u64, 8u64]));
+    /// let first = x.and_then_ref(|v| Option::from(v.get(0).copied()));
+    /// assert_eq!(first, Option::Some(7));
+    /// assert_eq!(x.and_then_ref(|v| Option::from(v.get(5).copied())), Option::None);
+    ///
+    /// let none: Option<Vector<u64>> = Option::None;
+    /// assert_eq!(none.and_then_ref(|v| Option::Some(v.len())), Option::None);
+    /// ```
+    pub fn and_then_ref<U, F>(&self, f: F) -> Option<U>
+    where
+        F: FnOnce(&T) -> Option<U>,
+    {
+        match self {
+            Option::Some(v) => f(v),
+            Option::None => Option::None,
+        }
+    }
+}
+
+impl<T> IntoIterator for Option<T> {
+    type Item = T;
+    type IntoIter = std::option::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.into_std().into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Option<T> {
+    type Item = &'a T;
+    type IntoIter = std::option::IntoIter<&'a T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.as_ref().into_iter()
+    }
+}
+
+impl<A, B> Option<(A, B)> {
+    /// Split an option of a pair into a pair of options
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let pair = Option::Some(1u64).zip(Option::Some("b"));
+    /// assert_eq!(pair.unzip(), (Option::Some(1u64), Option::Some("b")));
+    ///
+    /// let none: Option<(u64, &str)> = Option::None;
+    /// assert_eq!(none.unzip(), (Option::None, Option::None));
+    /// ```
+    pub fn unzip(self) -> (Option<A>, Option<B>) {
+        match self {
+            Option::Some((a, b)) => (Option::Some(a), Option::Some(b)),
+            Option::None => (Option::None, Option::None),
+        }
+    }
+}
+
+impl<T> Option<Option<T>> {
+    /// Collapse a nested option into a single level
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Option;
+    ///
+    /// let x: Option<Option<u64>> = Option::Some(Option::Some(42));
+    /// assert_eq!(x.flatten(), Option::Some(42));
+    ///
+    /// let y: Option<Option<u64>> = Option::Some(Option::None);
+    /// assert_eq!(y.flatten(), Option::None);
+    ///
+    /// let z: Option<Option<u64>> = Option::None;
+    /// assert_eq!(z.flatten(), Option::None);
+    /// ```
+    pub fn flatten(self) -> Option<T> {
+        match self {
+            Option::Some(inner) => inner,
+            Option::None => Option::None,
+        }
+    }
 }
 
 impl<T: fmt::Display> fmt::Display for Option<T> {
@@ -357,3 +1078,26 @@ impl<T: fmt::Display> fmt::Display for Option<T> {
         }
     }
 }
+
+impl<T> From<std::option::Option<T>> for Option<T> {
+    fn from(opt: std::option::Option<T>) -> Self {
+        match opt {
+            Some(v) => Option::Some(v),
+            None => Option::None,
+        }
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshSerialize> borsh::BorshSerialize for Option<T> {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        borsh::BorshSerialize::serialize(&self.as_ref().into_std(), writer)
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshDeserialize> borsh::BorshDeserialize for Option<T> {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        std::option::Option::<T>::deserialize_reader(reader).map(Option::from)
+    }
+}
@@ -7,13 +7,56 @@
 //! - String formatting
 //! - Encoding/decoding
 
+use crate::encoding::{self, Encode};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
 /// String type for Quantum smart contracts
 ///
 /// Strings are UTF-8 encoded byte sequences.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// Equality and hashing are both defined over the raw bytes, so strings
+/// can be used as `HashMap` and `HashSet` keys.
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::String;
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(String::from_str("alice"));
+/// set.insert(String::from_str("bob"));
+/// set.insert(String::from_str("alice"));
+/// assert_eq!(set.len(), 2);
+/// assert!(set.contains(&String::from_str("bob")));
+/// assert!(!set.contains(&String::from_str("carol")));
+/// ```
+///
+/// With the `borsh` feature, strings encode their raw bytes with a `u32`
+/// length prefix, so even a non-UTF-8 payload round-trips unchanged:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::String;
+///
+/// let s = String::from_str("hi");
+/// let bytes = borsh::to_vec(&s).unwrap();
+/// assert_eq!(bytes, [2, 0, 0, 0, b'h', b'i']);
+/// assert_eq!(borsh::from_slice::<String>(&bytes).unwrap(), s);
+///
+/// // Cuts "é" in half, leaving invalid UTF-8
+/// let raw = String::from_str("hé").substring(0, 2).unwrap();
+/// let bytes = borsh::to_vec(&raw).unwrap();
+/// assert_eq!(borsh::from_slice::<String>(&bytes).unwrap(), raw);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct String {
     bytes: Vec<u8>,
 }
@@ -123,6 +166,64 @@ impl String {
         &self.bytes
     }
 
+    /// Encode the string in the canonical byte format
+    ///
+    /// The encoding is a little-endian `u64` byte length followed by the
+    /// raw bytes, as described in [`crate::encoding`]. Unlike
+    /// [`as_bytes`](String::as_bytes) it is self-delimiting, so it can be
+    /// concatenated with other encoded values before hashing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hi");
+    /// assert_eq!(s.to_canonical_bytes(), [2, 0, 0, 0, 0, 0, 0, 0, b'h', b'i']);
+    ///
+    /// let same = String::from_str("hi");
+    /// assert_eq!(s.to_canonical_bytes(), same.to_canonical_bytes());
+    /// ```
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+
+    /// Decode a string from the canonical byte format
+    ///
+    /// The inverse of [`to_canonical_bytes`](String::to_canonical_bytes).
+    /// The payload is taken as-is without UTF-8 validation, so every encoded
+    /// string round-trips. Use [`from_bytes`](String::from_bytes) to build a
+    /// string from unprefixed UTF-8 instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Canonically encoded string
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The decoded string
+    /// * `Err(String)` - If `bytes` is truncated or has trailing data
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("héllo");
+    /// let bytes = s.to_canonical_bytes();
+    /// assert_eq!(String::from_canonical_bytes(&bytes), Ok(s.clone()));
+    ///
+    /// let mut extra = s.to_canonical_bytes();
+    /// extra.push(0);
+    /// assert!(String::from_canonical_bytes(&extra).is_err());
+    /// assert!(String::from_canonical_bytes(&[5, 0, 0, 0, 0, 0, 0, 0, b'h']).is_err());
+    /// ```
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Self, std::string::String> {
+        encoding::decode_exact(bytes)
+    }
+
     /// Convert to a Rust string slice
     ///
     /// # Returns
@@ -142,327 +243,1721 @@ impl String {
         std::str::from_utf8(&self.bytes).map_err(|_| "Invalid UTF-8".to_string())
     }
 
-    /// Append another string
-    ///
-    /// # Arguments
+    /// Iterate over the characters of the string
     ///
-    /// * `other` - String to append
+    /// Each invalid UTF-8 sequence yields a single `U+FFFD REPLACEMENT
+    /// CHARACTER`, matching `to_std_lossy`.
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let mut s = String::from_str("hello");
-    /// s.push_str(&String::from_str(" world"));
-    /// assert_eq!(s.as_str().unwrap(), "hello world");
+    /// let s = String::from_str("né🚀");
+    /// let chars: Vec<char> = s.chars().collect();
+    /// assert_eq!(chars, vec!['n', 'é', '🚀']);
     /// ```
-    pub fn push_str(&mut self, other: &String) {
-        self.bytes.extend_from_slice(&other.bytes);
+    pub fn chars(&self) -> impl Iterator<Item = char> + '_ {
+        self.bytes.utf8_chunks().flat_map(|chunk| {
+            let replacement = (!chunk.invalid().is_empty()).then_some(char::REPLACEMENT_CHARACTER);
+            chunk.valid().chars().chain(replacement)
+        })
     }
 
-    /// Append a single character
-    ///
-    /// # Arguments
+    /// Iterate over the characters of the string with their byte offsets
     ///
-    /// * `ch` - Character to append
+    /// Follows the same rule as `chars`: each invalid UTF-8 sequence yields a
+    /// single `U+FFFD REPLACEMENT CHARACTER`, reported at the byte offset
+    /// where the invalid sequence starts. Offsets of valid characters can be
+    /// passed straight to `substring`.
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let mut s = String::from_str("hello");
-    /// s.push_char('!');
-    /// assert_eq!(s.as_str().unwrap(), "hello!");
+    /// let ascii = String::from_str("abc");
+    /// let indices: Vec<(u64, char)> = ascii.char_indices().collect();
+    /// assert_eq!(indices, vec![(0, 'a'), (1, 'b'), (2, 'c')]);
+    ///
+    /// let s = String::from_str("né🚀!");
+    /// let indices: Vec<(u64, char)> = s.char_indices().collect();
+    /// assert_eq!(indices, vec![(0, 'n'), (1, 'é'), (3, '🚀'), (7, '!')]);
+    /// assert_eq!(s.substring(3, 7).unwrap().as_str().unwrap(), "🚀");
+    ///
+    /// // Cutting "é" in half leaves an invalid byte at offset 1
+    /// let invalid = String::from_str("hé").substring(0, 2).unwrap();
+    /// let indices: Vec<(u64, char)> = invalid.char_indices().collect();
+    /// assert_eq!(indices, vec![(0, 'h'), (1, char::REPLACEMENT_CHARACTER)]);
     /// ```
-    pub fn push_char(&mut self, ch: char) {
-        let mut buf = [0; 4];
-        let encoded = ch.encode_utf8(&mut buf);
-        self.bytes.extend_from_slice(encoded.as_bytes());
+    pub fn char_indices(&self) -> impl Iterator<Item = (u64, char)> + '_ {
+        let mut offset = 0usize;
+        self.bytes.utf8_chunks().flat_map(move |chunk| {
+            let start = offset;
+            let invalid_at = start + chunk.valid().len();
+            offset = invalid_at + chunk.invalid().len();
+            let replacement = (!chunk.invalid().is_empty())
+                .then_some((invalid_at as u64, char::REPLACEMENT_CHARACTER));
+            chunk
+                .valid()
+                .char_indices()
+                .map(move |(i, c)| ((start + i) as u64, c))
+                .chain(replacement)
+        })
     }
 
-    /// Check if the string contains a substring
-    ///
-    /// # Arguments
-    ///
-    /// * `needle` - Substring to search for
-    ///
-    /// # Returns
+    /// Get the number of characters (Unicode scalar values) in the string
     ///
-    /// true if the substring is found, false otherwise
+    /// Unlike `len`, which counts bytes, this counts characters. Invalid
+    /// UTF-8 sequences are counted as one replacement character each.
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello world");
-    /// assert!(s.contains(&String::from_str("world")));
-    /// assert!(!s.contains(&String::from_str("foo")));
+    /// let ascii = String::from_str("hello");
+    /// assert_eq!(ascii.len(), 5);
+    /// assert_eq!(ascii.char_count(), 5);
+    ///
+    /// let accented = String::from_str("café");
+    /// assert_eq!(accented.len(), 5);
+    /// assert_eq!(accented.char_count(), 4);
+    ///
+    /// let emoji = String::from_str("hi 🚀");
+    /// assert_eq!(emoji.len(), 7);
+    /// assert_eq!(emoji.char_count(), 4);
     /// ```
-    pub fn contains(&self, needle: &String) -> bool {
-        self.bytes
-            .windows(needle.bytes.len())
-            .any(|window| window == needle.bytes.as_slice())
+    pub fn char_count(&self) -> u64 {
+        self.chars().count() as u64
     }
 
-    /// Find the index of a substring
+    /// Get the character at a character position
+    ///
+    /// The index counts characters, not bytes, so multibyte characters are
+    /// never split.
     ///
     /// # Arguments
     ///
-    /// * `needle` - Substring to search for
+    /// * `index` - Character position
     ///
     /// # Returns
     ///
-    /// * `Some(index)` - If the substring is found
-    /// * `None` - If the substring is not found
+    /// * `Some(char)` - The character at `index`
+    /// * `None` - If `index` is past the last character
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello world");
-    /// assert_eq!(s.find(&String::from_str("world")), Some(6));
-    /// assert_eq!(s.find(&String::from_str("foo")), None);
+    /// let s = String::from_str("ab🚀c");
+    /// assert_eq!(s.char_at(0), Some('a'));
+    /// assert_eq!(s.char_at(2), Some('🚀'));
+    /// assert_eq!(s.char_at(3), Some('c'));
+    /// assert_eq!(s.char_at(4), None);
     /// ```
-    pub fn find(&self, needle: &String) -> Option<u64> {
-        self.bytes
-            .windows(needle.bytes.len())
-            .position(|window| window == needle.bytes.as_slice())
-            .map(|pos| pos as u64)
+    pub fn char_at(&self, index: u64) -> Option<char> {
+        let index = usize::try_from(index).ok()?;
+        self.chars().nth(index)
     }
 
-    /// Get a substring
-    ///
-    /// # Arguments
-    ///
-    /// * `start` - Start index
-    /// * `end` - End index (exclusive)
+    /// Convert to a Rust string, replacing invalid UTF-8 sequences
     ///
-    /// # Returns
-    ///
-    /// * `Ok(String)` - The substring
-    /// * `Err(String)` - If indices are out of bounds
+    /// Each invalid sequence is replaced with `U+FFFD REPLACEMENT CHARACTER`.
+    /// Use `std::string::String::try_from` to reject invalid content instead.
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello");
-    /// let sub = s.substring(1, 4).unwrap();
-    /// assert_eq!(sub.as_str().unwrap(), "ell");
+    /// let s = String::from("hello");
+    /// assert_eq!(s.to_std_lossy(), "hello");
+    /// assert_eq!(std::string::String::try_from(s).unwrap(), "hello");
+    ///
+    /// let s = String::from(std::string::String::from("héllo"));
+    /// assert_eq!(std::string::String::try_from(s).unwrap(), "héllo");
+    ///
+    /// // Cutting "é" in half leaves an incomplete UTF-8 sequence
+    /// let invalid = String::from("hé").substring(0, 2).unwrap();
+    /// assert_eq!(invalid.to_std_lossy(), "h\u{FFFD}");
+    /// assert!(std::string::String::try_from(invalid).is_err());
     /// ```
-    pub fn substring(&self, start: u64, end: u64) -> Result<String, std::string::String> {
-        let start = start as usize;
-        let end = end as usize;
-
-        if start > self.bytes.len() || end > self.bytes.len() || start > end {
-            return Err("Invalid substring indices".to_string());
-        }
-
-        Ok(String {
-            bytes: self.bytes[start..end].to_vec(),
-        })
+    pub fn to_std_lossy(&self) -> std::string::String {
+        std::string::String::from_utf8_lossy(&self.bytes).into_owned()
     }
 
-    /// Convert to uppercase
+    /// Encode the underlying bytes as lowercase hex
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello");
-    /// let upper = s.to_uppercase();
-    /// assert_eq!(upper.as_str().unwrap(), "HELLO");
+    /// let s = String::from_str("hi");
+    /// assert_eq!(s.to_hex().as_str().unwrap(), "6869");
     /// ```
-    pub fn to_uppercase(&self) -> String {
-        if let Ok(s) = self.as_str() {
-            String::from_str(&s.to_uppercase())
-        } else {
-            self.clone()
+    pub fn to_hex(&self) -> String {
+        const HEX: &[u8; 16] = b"0123456789abcdef";
+
+        let mut bytes = Vec::with_capacity(self.bytes.len() * 2);
+        for &b in &self.bytes {
+            bytes.push(HEX[(b >> 4) as usize]);
+            bytes.push(HEX[(b & 0x0f) as usize]);
         }
+        String { bytes }
     }
 
-    /// Convert to lowercase
+    /// Decode a hex string into raw bytes
+    ///
+    /// Both uppercase and lowercase digits are accepted. The decoded bytes
+    /// are stored as-is and need not be valid UTF-8.
+    ///
+    /// # Arguments
+    ///
+    /// * `hex` - Hex-encoded text
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The decoded bytes
+    /// * `Err(String)` - If the input has odd length or a non-hex character
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("HELLO");
-    /// let lower = s.to_lowercase();
-    /// assert_eq!(lower.as_str().unwrap(), "hello");
+    /// let s = String::from_hex("00ff7F").unwrap();
+    /// assert_eq!(s.as_bytes(), &[0x00, 0xff, 0x7f]);
+    /// assert_eq!(s.to_hex().as_str().unwrap(), "00ff7f");
+    ///
+    /// let raw = String::from_hex("deadbeef0080c3").unwrap();
+    /// let round_trip = String::from_hex(raw.to_hex().as_str().unwrap()).unwrap();
+    /// assert_eq!(round_trip, raw);
+    ///
+    /// assert!(String::from_hex("abc").is_err());
+    /// assert!(String::from_hex("zz").is_err());
     /// ```
-    pub fn to_lowercase(&self) -> String {
-        if let Ok(s) = self.as_str() {
-            String::from_str(&s.to_lowercase())
-        } else {
-            self.clone()
+    pub fn from_hex(hex: &str) -> Result<String, std::string::String> {
+        fn nibble(c: u8) -> Result<u8, std::string::String> {
+            match c {
+                b'0'..=b'9' => Ok(c - b'0'),
+                b'a'..=b'f' => Ok(c - b'a' + 10),
+                b'A'..=b'F' => Ok(c - b'A' + 10),
+                _ => Err(format!("Invalid hex character: {:?}", c as char)),
+            }
         }
+
+        let input = hex.as_bytes();
+        if !input.len().is_multiple_of(2) {
+            return Err("Hex string has odd length".to_string());
+        }
+
+        let bytes = input
+            .chunks_exact(2)
+            .map(|pair| Ok((nibble(pair[0])? << 4) | nibble(pair[1])?))
+            .collect::<Result<Vec<u8>, std::string::String>>()?;
+
+        Ok(String { bytes })
     }
 
-    /// Trim whitespace from both ends
+    /// Encode the underlying bytes as base64
+    ///
+    /// Uses the standard alphabet (RFC 4648) with `=` padding.
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("  hello  ");
-    /// let trimmed = s.trim();
-    /// assert_eq!(trimmed.as_str().unwrap(), "hello");
+    /// assert_eq!(String::from_str("").to_base64().as_str().unwrap(), "");
+    /// assert_eq!(String::from_str("f").to_base64().as_str().unwrap(), "Zg==");
+    /// assert_eq!(String::from_str("fo").to_base64().as_str().unwrap(), "Zm8=");
+    /// assert_eq!(String::from_str("foo").to_base64().as_str().unwrap(), "Zm9v");
     /// ```
-    pub fn trim(&self) -> String {
-        if let Ok(s) = self.as_str() {
-            String::from_str(s.trim())
-        } else {
-            self.clone()
+    pub fn to_base64(&self) -> String {
+        const ALPHABET: &[u8; 64] =
+            b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+        let mut bytes = Vec::with_capacity(self.bytes.len().div_ceil(3) * 4);
+        for chunk in self.bytes.chunks(3) {
+            let b0 = chunk[0] as u32;
+            let b1 = chunk.get(1).copied().unwrap_or(0) as u32;
+            let b2 = chunk.get(2).copied().unwrap_or(0) as u32;
+            let triple = (b0 << 16) | (b1 << 8) | b2;
+
+            bytes.push(ALPHABET[(triple >> 18) as usize & 0x3f]);
+            bytes.push(ALPHABET[(triple >> 12) as usize & 0x3f]);
+            if chunk.len() > 1 {
+                bytes.push(ALPHABET[(triple >> 6) as usize & 0x3f]);
+            } else {
+                bytes.push(b'=');
+            }
+            if chunk.len() > 2 {
+                bytes.push(ALPHABET[triple as usize & 0x3f]);
+            } else {
+                bytes.push(b'=');
+            }
         }
+        String { bytes }
     }
 
-    /// Split the string by a delimiter
+    /// Decode base64 text into raw bytes
+    ///
+    /// Expects the standard alphabet (RFC 4648) with `=` padding. Whitespace
+    /// is not tolerated and is rejected like any other invalid character.
+    /// The decoded bytes are stored as-is and need not be valid UTF-8.
+    ///
+    /// Only the canonical encoding is accepted: the unused low bits of the
+    /// final character before padding must be zero, so every byte sequence
+    /// has exactly one accepted base64 form.
     ///
     /// # Arguments
     ///
-    /// * `delimiter` - Delimiter string
+    /// * `s` - Base64-encoded text
     ///
     /// # Returns
     ///
-    /// Vector of substrings
+    /// * `Ok(String)` - The decoded bytes
+    /// * `Err(String)` - If the length is not a multiple of 4, a character is
+    ///   outside the alphabet, padding is misplaced, or the padding bits are
+    ///   not zero
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello,world,foo");
-    /// let parts = s.split(&String::from_str(","));
-    /// assert_eq!(parts.len(), 3);
+    /// assert!(String::from_base64("").unwrap().is_empty());
+    /// assert_eq!(String::from_base64("Zg==").unwrap().as_bytes(), b"f");
+    /// assert_eq!(String::from_base64("Zm8=").unwrap().as_bytes(), b"fo");
+    /// assert_eq!(String::from_base64("/w==").unwrap().as_bytes(), &[0xff]);
+    ///
+    /// assert!(String::from_base64("Zm9").is_err());
+    /// assert!(String::from_base64("Zm9v!A==").is_err());
+    /// assert!(String::from_base64("Zm 9").is_err());
+    /// assert!(String::from_base64("Z=9v").is_err());
+    ///
+    /// // Same bytes as "Zg==" and "Zm8=", but with non-zero padding bits
+    /// assert!(String::from_base64("Zh==").is_err());
+    /// assert!(String::from_base64("Zm9=").is_err());
     /// ```
-    pub fn split(&self, delimiter: &String) -> Vec<String> {
-        if delimiter.is_empty() {
-            return vec![self.clone()];
+    pub fn from_base64(s: &str) -> Result<String, std::string::String> {
+        fn sextet(c: u8) -> Result<u32, std::string::String> {
+            match c {
+                b'A'..=b'Z' => Ok((c - b'A') as u32),
+                b'a'..=b'z' => Ok((c - b'a' + 26) as u32),
+                b'0'..=b'9' => Ok((c - b'0' + 52) as u32),
+                b'+' => Ok(62),
+                b'/' => Ok(63),
+                _ => Err(format!("Invalid base64 character: {:?}", c as char)),
+            }
         }
 
-        let mut result = Vec::new();
-        let mut start = 0;
+        let input = s.as_bytes();
+        if !input.len().is_multiple_of(4) {
+            return Err("Base64 length must be a multiple of 4".to_string());
+        }
 
-        while let Some(pos) = self
-            .substring(start as u64, self.bytes.len() as u64)
-            .ok()
-            .and_then(|s| s.find(delimiter))
-        {
-            let pos = pos as usize + start;
-            if let Ok(part) = self.substring(start as u64, pos as u64) {
-                result.push(part);
+        let mut bytes = Vec::with_capacity(input.len() / 4 * 3);
+        let last_chunk = input.len() / 4;
+        for (i, chunk) in input.chunks_exact(4).enumerate() {
+            let padding = chunk.iter().rev().take_while(|&&c| c == b'=').count();
+            if padding > 2 || (padding > 0 && i + 1 != last_chunk) {
+                return Err("Invalid base64 padding".to_string());
             }
-            start = pos + delimiter.bytes.len();
-        }
 
-        if start <= self.bytes.len() {
-            if let Ok(part) = self.substring(start as u64, self.bytes.len() as u64) {
-                result.push(part);
+            let mut triple = 0u32;
+            for &c in &chunk[..4 - padding] {
+                triple = (triple << 6) | sextet(c)?;
+            }
+            triple <<= 6 * padding as u32;
+            if triple & ((1u32 << (8 * padding)) - 1) != 0 {
+                return Err("Non-canonical base64: padding bits must be zero".to_string());
+            }
+
+            bytes.push((triple >> 16) as u8);
+            if padding < 2 {
+                bytes.push((triple >> 8) as u8);
+            }
+            if padding < 1 {
+                bytes.push(triple as u8);
             }
         }
 
-        result
+        Ok(String { bytes })
     }
 
-    /// Repeat the string n times
+    /// Append another string
     ///
     /// # Arguments
     ///
-    /// * `n` - Number of repetitions
+    /// * `other` - String to append
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("ab");
-    /// let repeated = s.repeat(3);
-    /// assert_eq!(repeated.as_str().unwrap(), "ababab");
+    /// let mut s = String::from_str("hello");
+    /// s.push_str(&String::from_str(" world"));
+    /// assert_eq!(s.as_str().unwrap(), "hello world");
     /// ```
-    pub fn repeat(&self, n: u64) -> String {
-        let mut result = String::new();
-        for _ in 0..n {
-            result.push_str(self);
-        }
-        result
+    pub fn push_str(&mut self, other: &String) {
+        self.bytes.extend_from_slice(&other.bytes);
     }
 
-    /// Replace all occurrences of a substring
+    /// Append a single character
     ///
     /// # Arguments
     ///
-    /// * `from` - Substring to replace
-    /// * `to` - Replacement string
+    /// * `ch` - Character to append
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello world");
-    /// let replaced = s.replace(&String::from_str("world"), &String::from_str("Quantum"));
-    /// assert_eq!(replaced.as_str().unwrap(), "hello Quantum");
+    /// let mut s = String::from_str("hello");
+    /// s.push_char('!');
+    /// assert_eq!(s.as_str().unwrap(), "hello!");
     /// ```
-    pub fn replace(&self, from: &String, to: &String) -> String {
-        let parts = self.split(from);
-        let mut result = String::new();
+    pub fn push_char(&mut self, ch: char) {
+        let mut buf = [0; 4];
+        let encoded = ch.encode_utf8(&mut buf);
+        self.bytes.extend_from_slice(encoded.as_bytes());
+    }
 
-        for (i, part) in parts.iter().enumerate() {
-            if i > 0 {
-                result.push_str(to);
-            }
-            result.push_str(part);
+    /// Check that a byte index is in bounds and not inside a multibyte character
+    fn check_char_boundary(&self, index: u64) -> Result<usize, std::string::String> {
+        if index > self.len() {
+            return Err(format!(
+                "Index out of bounds: {} > {}",
+                index,
+                self.bytes.len()
+            ));
         }
-
-        result
+        let idx = index as usize;
+        // UTF-8 continuation bytes have the form 0b10xxxxxx
+        if self.bytes.get(idx).is_some_and(|b| b & 0xC0 == 0x80) {
+            return Err(format!("Index {} is not a char boundary", index));
+        }
+        Ok(idx)
     }
 
-    /// Check if the string starts with a prefix
+    /// Insert a string at a byte index
     ///
     /// # Arguments
     ///
-    /// * `prefix` - Prefix to check
+    /// * `index` - Byte index to insert at
+    /// * `s` - String to insert
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the string was inserted
+    /// * `Err(String)` - If `index` is out of bounds or inside a multibyte character
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello world");
-    /// assert!(s.starts_with(&String::from_str("hello")));
-    /// assert!(!s.starts_with(&String::from_str("world")));
+    /// let mut s = String::from_str("ac");
+    /// s.insert_str(1, &String::from_str("b")).unwrap();
+    /// s.insert_str(0, &String::from_str("<")).unwrap();
+    /// s.insert_str(4, &String::from_str(">")).unwrap();
+    /// assert_eq!(s.as_str().unwrap(), "<abc>");
+    ///
+    /// let mut s = String::from_str("é");
+    /// assert!(s.insert_str(1, &String::from_str("x")).is_err());
+    /// assert!(s.insert_str(3, &String::from_str("x")).is_err());
+    /// assert_eq!(s.as_str().unwrap(), "é");
     /// ```
-    pub fn starts_with(&self, prefix: &String) -> bool {
-        self.bytes.starts_with(&prefix.bytes)
+    pub fn insert_str(&mut self, index: u64, s: &String) -> Result<(), std::string::String> {
+        let idx = self.check_char_boundary(index)?;
+        self.bytes.splice(idx..idx, s.bytes.iter().copied());
+        Ok(())
     }
 
-    /// Check if the string ends with a suffix
+    /// Remove a byte range from the string
     ///
     /// # Arguments
     ///
-    /// * `suffix` - Suffix to check
+    /// * `start` - Start byte index (inclusive)
+    /// * `end` - End byte index (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the range was removed
+    /// * `Err(String)` - If the range is inverted, out of bounds, or splits a
+    ///   multibyte character
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::String;
     ///
-    /// let s = String::from_str("hello world");
-    /// assert!(s.ends_with(&String::from_str("world")));
+    /// let mut s = String::from_str("hello, world");
+    /// s.remove_range(5, 7).unwrap();
+    /// assert_eq!(s.as_str().unwrap(), "helloworld");
+    ///
+    /// let mut s = String::from_str("aéb");
+    /// assert!(s.remove_range(1, 2).is_err());
+    /// assert_eq!(s.remove_range(2, 1).unwrap_err(), "Invalid range: start 2 > end 1");
+    /// assert_eq!(s.remove_range(1, 9).unwrap_err(), "Index out of bounds: 9 > 4");
+    /// s.remove_range(1, 3).unwrap();
+    /// assert_eq!(s.as_str().unwrap(), "ab");
+    /// ```
+    pub fn remove_range(&mut self, start: u64, end: u64) -> Result<(), std::string::String> {
+        crate::math::range_len(start, end)?;
+        let end_idx = self.check_char_boundary(end)?;
+        let start_idx = self.check_char_boundary(start)?;
+        self.bytes.drain(start_idx..end_idx);
+        Ok(())
+    }
+
+    /// Check if the string contains a substring
+    ///
+    /// # Arguments
+    ///
+    /// * `needle` - Substring to search for
+    ///
+    /// # Returns
+    ///
+    /// true if the substring is found, false otherwise
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello world");
+    /// assert!(s.contains(&String::from_str("world")));
+    /// assert!(!s.contains(&String::from_str("foo")));
+    /// ```
+    pub fn contains(&self, needle: &String) -> bool {
+        self.bytes
+            .windows(needle.bytes.len())
+            .any(|window| window == needle.bytes.as_slice())
+    }
+
+    /// Check equality ignoring ASCII case
+    ///
+    /// Only ASCII letters are case-folded; all other bytes must match
+    /// exactly. No allocation is performed.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - String to compare with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("Hello");
+    /// assert!(s.eq_ignore_ascii_case(&String::from_str("hello")));
+    /// assert!(!s.eq_ignore_ascii_case(&String::from_str("help")));
+    /// ```
+    pub fn eq_ignore_ascii_case(&self, other: &String) -> bool {
+        self.bytes.eq_ignore_ascii_case(&other.bytes)
+    }
+
+    /// Check if the string contains a substring, ignoring ASCII case
+    ///
+    /// Only ASCII letters are case-folded; all other bytes must match
+    /// exactly. No allocation is performed. An empty needle always matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `needle` - Substring to search for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello world");
+    /// assert!(s.contains_ignore_case(&String::from_str("WORLD")));
+    /// assert!(!s.contains_ignore_case(&String::from_str("planet")));
+    /// ```
+    pub fn contains_ignore_case(&self, needle: &String) -> bool {
+        if needle.is_empty() {
+            return true;
+        }
+
+        self.bytes
+            .windows(needle.bytes.len())
+            .any(|window| window.eq_ignore_ascii_case(&needle.bytes))
+    }
+
+    /// Find the index of a substring
+    ///
+    /// # Arguments
+    ///
+    /// * `needle` - Substring to search for
+    ///
+    /// # Returns
+    ///
+    /// * `Some(index)` - If the substring is found
+    /// * `None` - If the substring is not found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello world");
+    /// assert_eq!(s.find(&String::from_str("world")), Some(6));
+    /// assert_eq!(s.find(&String::from_str("foo")), None);
+    /// ```
+    pub fn find(&self, needle: &String) -> Option<u64> {
+        self.bytes
+            .windows(needle.bytes.len())
+            .position(|window| window == needle.bytes.as_slice())
+            .map(|pos| pos as u64)
+    }
+
+    /// Find the last occurrence of a substring
+    ///
+    /// An empty needle matches at the end of the string, so its index is
+    /// the string length.
+    ///
+    /// # Arguments
+    ///
+    /// * `needle` - Substring to search for
+    ///
+    /// # Returns
+    ///
+    /// * `Some(index)` - Byte index of the last occurrence
+    /// * `None` - If the substring is not found
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let path = String::from_str("a/b/c.txt");
+    /// let sep = String::from_str("/");
+    /// assert_eq!(path.rfind(&sep), Some(3));
+    /// assert_eq!(path.find(&sep), Some(1));
+    /// assert_eq!(path.rfind(&String::from_str(".")), Some(5));
+    /// assert_eq!(path.rfind(&String::from_str("z")), None);
+    /// assert_eq!(path.rfind(&String::new()), Some(9));
+    /// ```
+    pub fn rfind(&self, needle: &String) -> Option<u64> {
+        if needle.bytes.is_empty() {
+            return Some(self.len());
+        }
+        self.bytes
+            .windows(needle.bytes.len())
+            .rposition(|window| window == needle.bytes.as_slice())
+            .map(|pos| pos as u64)
+    }
+
+    /// Count the non-overlapping occurrences of a substring
+    ///
+    /// Matches are counted left to right, resuming after each match. An
+    /// empty needle counts as zero occurrences.
+    ///
+    /// # Arguments
+    ///
+    /// * `needle` - Substring to count
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let template = String::from_str("Hi {}, you owe {} to {}");
+    /// assert_eq!(template.count_matches(&String::from_str("{}")), 3);
+    /// assert_eq!(template.count_matches(&String::from_str("owe")), 1);
+    /// assert_eq!(template.count_matches(&String::from_str("$")), 0);
+    /// assert_eq!(template.count_matches(&String::new()), 0);
+    ///
+    /// // Adjacent matches count separately; overlapping ones do not
+    /// let s = String::from_str("aaaa");
+    /// assert_eq!(s.count_matches(&String::from_str("aa")), 2);
+    /// assert_eq!(s.count_matches(&String::from_str("aaa")), 1);
+    /// ```
+    pub fn count_matches(&self, needle: &String) -> u64 {
+        let n = needle.bytes.len();
+        if n == 0 {
+            return 0;
+        }
+
+        let mut count = 0;
+        let mut i = 0;
+        while i + n <= self.bytes.len() {
+            if self.bytes[i..i + n] == needle.bytes[..] {
+                count += 1;
+                i += n;
+            } else {
+                i += 1;
+            }
+        }
+        count
+    }
+
+    /// Get a substring
+    ///
+    /// # Arguments
+    ///
+    /// * `start` - Start index
+    /// * `end` - End index (exclusive)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(String)` - The substring
+    /// * `Err(String)` - If `start > end` or `end` is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello");
+    /// let sub = s.substring(1, 4).unwrap();
+    /// assert_eq!(sub.as_str().unwrap(), "ell");
+    ///
+    /// assert_eq!(s.substring(3, 2).unwrap_err(), "Invalid range: start 3 > end 2");
+    /// assert_eq!(s.substring(2, 6).unwrap_err(), "Index out of bounds: 6 > 5");
+    /// ```
+    pub fn substring(&self, start: u64, end: u64) -> Result<String, std::string::String> {
+        let len = crate::math::range_len(start, end)? as usize;
+        if end > self.len() {
+            return Err(format!(
+                "Index out of bounds: {} > {}",
+                end,
+                self.bytes.len()
+            ));
+        }
+
+        let start = start as usize;
+        Ok(String {
+            bytes: self.bytes[start..start + len].to_vec(),
+        })
+    }
+
+    /// Convert to uppercase
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello");
+    /// let upper = s.to_uppercase();
+    /// assert_eq!(upper.as_str().unwrap(), "HELLO");
+    /// ```
+    pub fn to_uppercase(&self) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(&s.to_uppercase())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Convert to lowercase
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("HELLO");
+    /// let lower = s.to_lowercase();
+    /// assert_eq!(lower.as_str().unwrap(), "hello");
+    /// ```
+    pub fn to_lowercase(&self) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(&s.to_lowercase())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Check if every byte is ASCII
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// assert!(String::from_str("GET /index.html").is_ascii());
+    /// assert!(String::new().is_ascii());
+    /// assert!(!String::from_str("café").is_ascii());
+    /// ```
+    pub fn is_ascii(&self) -> bool {
+        self.bytes.is_ascii()
+    }
+
+    /// Convert ASCII letters to uppercase
+    ///
+    /// Works byte by byte: only `a`-`z` change, and every other byte
+    /// (including non-ASCII and invalid UTF-8) is copied unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("Content-Type: café");
+    /// assert_eq!(s.to_ascii_uppercase().as_str().unwrap(), "CONTENT-TYPE: CAFé");
+    /// ```
+    pub fn to_ascii_uppercase(&self) -> String {
+        String {
+            bytes: self.bytes.to_ascii_uppercase(),
+        }
+    }
+
+    /// Convert ASCII letters to lowercase
+    ///
+    /// Works byte by byte: only `A`-`Z` change, and every other byte
+    /// (including non-ASCII and invalid UTF-8) is copied unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("Content-Type: ÉTÉ");
+    /// assert_eq!(s.to_ascii_lowercase().as_str().unwrap(), "content-type: ÉtÉ");
+    /// ```
+    pub fn to_ascii_lowercase(&self) -> String {
+        String {
+            bytes: self.bytes.to_ascii_lowercase(),
+        }
+    }
+
+    /// Trim whitespace from both ends
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("  hello  ");
+    /// let trimmed = s.trim();
+    /// assert_eq!(trimmed.as_str().unwrap(), "hello");
+    /// ```
+    pub fn trim(&self) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(s.trim())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Trim leading whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("  hello\n");
+    /// let trimmed = s.trim_start();
+    /// assert_eq!(trimmed.as_str().unwrap(), "hello\n");
+    /// ```
+    pub fn trim_start(&self) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(s.trim_start())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Trim trailing whitespace
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("\t hello  \n\n");
+    /// let trimmed = s.trim_end();
+    /// assert_eq!(trimmed.as_str().unwrap(), "\t hello");
+    /// ```
+    pub fn trim_end(&self) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(s.trim_end())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Trim every occurrence of a character from both ends
+    ///
+    /// Invalid UTF-8 strings are returned unchanged, like `trim`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch` - Character to remove
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let quoted = String::from_str("\"hi\"");
+    /// assert_eq!(quoted.trim_matches('"').as_str().unwrap(), "hi");
+    ///
+    /// let padded = String::from_str("--a-b--");
+    /// assert_eq!(padded.trim_matches('-').as_str().unwrap(), "a-b");
+    /// ```
+    pub fn trim_matches(&self, ch: char) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(s.trim_matches(ch))
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Trim every leading occurrence of a character
+    ///
+    /// Invalid UTF-8 strings are returned unchanged, like `trim_start`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch` - Character to remove
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("000123");
+    /// assert_eq!(s.trim_start_matches('0').as_str().unwrap(), "123");
+    ///
+    /// let zero = String::from_str("000");
+    /// assert!(zero.trim_start_matches('0').is_empty());
+    /// ```
+    pub fn trim_start_matches(&self, ch: char) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(s.trim_start_matches(ch))
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Trim every trailing occurrence of a character
+    ///
+    /// Invalid UTF-8 strings are returned unchanged, like `trim_end`.
+    ///
+    /// # Arguments
+    ///
+    /// * `ch` - Character to remove
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("1.2500");
+    /// assert_eq!(s.trim_end_matches('0').as_str().unwrap(), "1.25");
+    ///
+    /// let quoted = String::from_str("\"hi\"");
+    /// assert_eq!(quoted.trim_end_matches('"').as_str().unwrap(), "\"hi");
+    /// ```
+    pub fn trim_end_matches(&self, ch: char) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from_str(s.trim_end_matches(ch))
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Number of fill characters needed to reach `width` characters
+    fn pad_count(&self, width: u64) -> u64 {
+        width.saturating_sub(self.char_count())
+    }
+
+    /// Pad the start of the string to a character width
+    ///
+    /// Width is measured in characters, not bytes. Strings already at least
+    /// `width` characters long are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Target width in characters
+    /// * `fill` - Character to pad with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("42");
+    /// assert_eq!(s.pad_start(5, '0').as_str().unwrap(), "00042");
+    /// assert_eq!(s.pad_start(4, '·').as_str().unwrap(), "··42");
+    ///
+    /// let wide = String::from_str("héllo");
+    /// assert_eq!(wide.pad_start(3, ' '), wide);
+    /// ```
+    pub fn pad_start(&self, width: u64, fill: char) -> String {
+        let mut result = String::new();
+        for _ in 0..self.pad_count(width) {
+            result.push_char(fill);
+        }
+        result.push_str(self);
+        result
+    }
+
+    /// Pad the end of the string to a character width
+    ///
+    /// Width is measured in characters, not bytes. Strings already at least
+    /// `width` characters long are returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `width` - Target width in characters
+    /// * `fill` - Character to pad with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("né");
+    /// let padded = s.pad_end(4, '🚀');
+    /// assert_eq!(padded.as_str().unwrap(), "né🚀🚀");
+    /// assert_eq!(padded.char_count(), 4);
+    ///
+    /// assert_eq!(s.pad_end(2, '-'), s);
+    /// ```
+    pub fn pad_end(&self, width: u64, fill: char) -> String {
+        let mut result = self.clone();
+        for _ in 0..self.pad_count(width) {
+            result.push_char(fill);
+        }
+        result
+    }
+
+    /// Split the string by a delimiter
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Delimiter string
+    ///
+    /// # Returns
+    ///
+    /// Vector of substrings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello,world,foo");
+    /// let parts = s.split(&String::from_str(","));
+    /// assert_eq!(parts.len(), 3);
+    /// ```
+    pub fn split(&self, delimiter: &String) -> Vec<String> {
+        if delimiter.is_empty() {
+            return vec![self.clone()];
+        }
+
+        let mut result = Vec::new();
+        let mut start = 0;
+
+        while let Some(pos) = self
+            .substring(start as u64, self.bytes.len() as u64)
+            .ok()
+            .and_then(|s| s.find(delimiter))
+        {
+            let pos = pos as usize + start;
+            if let Ok(part) = self.substring(start as u64, pos as u64) {
+                result.push(part);
+            }
+            start = pos + delimiter.bytes.len();
+        }
+
+        if start <= self.bytes.len() {
+            if let Ok(part) = self.substring(start as u64, self.bytes.len() as u64) {
+                result.push(part);
+            }
+        }
+
+        result
+    }
+
+    /// Split the string by a delimiter into at most `n` pieces
+    ///
+    /// Splits at the first `n - 1` delimiters; the last piece keeps the rest
+    /// of the string, including any further delimiters. `n == 0` yields no
+    /// pieces, and an empty delimiter does not split, like `split`.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Maximum number of pieces
+    /// * `delimiter` - Delimiter string
+    ///
+    /// # Returns
+    ///
+    /// Vector of substrings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("key=value=with=equals");
+    /// let eq = String::from_str("=");
+    /// let parts = |n| -> Vec<std::string::String> {
+    ///     s.splitn(n, &eq).iter().map(|p| p.to_std_lossy()).collect()
+    /// };
+    ///
+    /// assert_eq!(parts(1), vec!["key=value=with=equals"]);
+    /// assert_eq!(parts(2), vec!["key", "value=with=equals"]);
+    /// assert_eq!(parts(10), vec!["key", "value", "with", "equals"]);
+    /// assert!(parts(0).is_empty());
+    /// ```
+    pub fn splitn(&self, n: u64, delimiter: &String) -> Vec<String> {
+        if n == 0 {
+            return Vec::new();
+        }
+        if delimiter.is_empty() {
+            return vec![self.clone()];
+        }
+
+        let d = delimiter.bytes.as_slice();
+        let mut result = Vec::new();
+        let mut start = 0;
+
+        while (result.len() as u64) < n - 1 {
+            let Some(pos) = self.bytes[start..]
+                .windows(d.len())
+                .position(|window| window == d)
+            else {
+                break;
+            };
+            result.push(String {
+                bytes: self.bytes[start..start + pos].to_vec(),
+            });
+            start += pos + d.len();
+        }
+
+        result.push(String {
+            bytes: self.bytes[start..].to_vec(),
+        });
+        result
+    }
+
+    /// Split the string by a delimiter, keeping the byte span of each piece
+    ///
+    /// Produces the same pieces as `split`, each paired with its
+    /// `[start, end)` byte range in the original string. The gaps between
+    /// consecutive spans are exactly the delimiters, so the original can be
+    /// reconstructed from the spans.
+    ///
+    /// # Arguments
+    ///
+    /// * `delimiter` - Delimiter string
+    ///
+    /// # Returns
+    ///
+    /// Vector of `(start, end, substring)` tuples
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("a,bb,c");
+    /// let spans = s.split_with_spans(&String::from_str(","));
+    /// assert_eq!(
+    ///     spans,
+    ///     vec![
+    ///         (0, 1, String::from_str("a")),
+    ///         (2, 4, String::from_str("bb")),
+    ///         (5, 6, String::from_str("c")),
+    ///     ]
+    /// );
+    /// ```
+    pub fn split_with_spans(&self, delimiter: &String) -> Vec<(u64, u64, String)> {
+        let len = self.bytes.len();
+        if delimiter.is_empty() {
+            return vec![(0, len as u64, self.clone())];
+        }
+
+        let delim = delimiter.bytes.as_slice();
+        let mut result = Vec::new();
+        let mut start = 0;
+        let mut i = 0;
+
+        while i + delim.len() <= len {
+            if &self.bytes[i..i + delim.len()] == delim {
+                result.push((
+                    start as u64,
+                    i as u64,
+                    String {
+                        bytes: self.bytes[start..i].to_vec(),
+                    },
+                ));
+                i += delim.len();
+                start = i;
+            } else {
+                i += 1;
+            }
+        }
+
+        result.push((
+            start as u64,
+            len as u64,
+            String {
+                bytes: self.bytes[start..].to_vec(),
+            },
+        ));
+        result
+    }
+
+    /// Split the string on runs of whitespace
+    ///
+    /// Leading and trailing whitespace is ignored and consecutive
+    /// whitespace never produces empty parts. If the string is not valid
+    /// UTF-8 it is returned as a single part.
+    ///
+    /// # Returns
+    ///
+    /// Vector of non-empty substrings
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("  transfer \t 100\n  coins ");
+    /// let parts = s.split_whitespace();
+    /// assert_eq!(parts.len(), 3);
+    /// assert_eq!(parts[0].as_str().unwrap(), "transfer");
+    /// assert_eq!(parts[1].as_str().unwrap(), "100");
+    /// assert_eq!(parts[2].as_str().unwrap(), "coins");
+    ///
+    /// assert!(String::from_str(" \t ").split_whitespace().is_empty());
+    /// ```
+    pub fn split_whitespace(&self) -> Vec<String> {
+        if let Ok(s) = self.as_str() {
+            s.split_whitespace().map(String::from_str).collect()
+        } else {
+            vec![self.clone()]
+        }
+    }
+
+    /// Split the string into lines
+    ///
+    /// Lines end with `\n` or `\r\n`; the line terminators are not included.
+    /// A trailing terminator does not produce a final empty line. If the
+    /// string is not valid UTF-8 it is returned as a single line.
+    ///
+    /// # Returns
+    ///
+    /// Vector of lines
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("first\r\nsecond\n\nfourth\n");
+    /// let lines = s.lines();
+    /// assert_eq!(lines.len(), 4);
+    /// assert_eq!(lines[0].as_str().unwrap(), "first");
+    /// assert_eq!(lines[1].as_str().unwrap(), "second");
+    /// assert!(lines[2].is_empty());
+    /// assert_eq!(lines[3].as_str().unwrap(), "fourth");
+    /// ```
+    pub fn lines(&self) -> Vec<String> {
+        if let Ok(s) = self.as_str() {
+            s.lines().map(String::from_str).collect()
+        } else {
+            vec![self.clone()]
+        }
+    }
+
+    /// Join strings with a separator
+    ///
+    /// This is the inverse of `split`.
+    ///
+    /// # Arguments
+    ///
+    /// * `parts` - Strings to concatenate
+    /// * `separator` - String inserted between consecutive parts
+    ///
+    /// # Returns
+    ///
+    /// The joined string, or an empty string if `parts` is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let comma = String::from_str(",");
+    /// assert!(String::join(&[], &comma).is_empty());
+    ///
+    /// let one = [String::from_str("a")];
+    /// assert_eq!(String::join(&one, &comma).as_str().unwrap(), "a");
+    ///
+    /// let parts = String::from_str("a,b,c").split(&comma);
+    /// assert_eq!(String::join(&parts, &comma).as_str().unwrap(), "a,b,c");
+    /// assert_eq!(String::join(&parts, &String::new()).as_str().unwrap(), "abc");
+    /// ```
+    pub fn join(parts: &[String], separator: &String) -> String {
+        let mut result = String::new();
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                result.push_str(separator);
+            }
+            result.push_str(part);
+        }
+        result
+    }
+
+    /// Repeat the string n times
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Number of repetitions
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("ab");
+    /// let repeated = s.repeat(3);
+    /// assert_eq!(repeated.as_str().unwrap(), "ababab");
+    /// ```
+    pub fn repeat(&self, n: u64) -> String {
+        let mut result = String::new();
+        for _ in 0..n {
+            result.push_str(self);
+        }
+        result
+    }
+
+    /// Reverse the string by character
+    ///
+    /// Characters (Unicode scalar values) are reversed, not bytes, so the
+    /// result is valid UTF-8. If the string is not valid UTF-8 it is
+    /// returned unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("abc");
+    /// assert_eq!(s.reverse().as_str().unwrap(), "cba");
+    ///
+    /// let s = String::from_str("né🚀");
+    /// assert_eq!(s.reverse().as_str().unwrap(), "🚀én");
+    /// ```
+    pub fn reverse(&self) -> String {
+        if let Ok(s) = self.as_str() {
+            String::from(s.chars().rev().collect::<std::string::String>())
+        } else {
+            self.clone()
+        }
+    }
+
+    /// Replace all occurrences of a substring
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Substring to replace
+    /// * `to` - Replacement string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello world");
+    /// let replaced = s.replace(&String::from_str("world"), &String::from_str("Quantum"));
+    /// assert_eq!(replaced.as_str().unwrap(), "hello Quantum");
+    /// ```
+    pub fn replace(&self, from: &String, to: &String) -> String {
+        let parts = self.split(from);
+        let mut result = String::new();
+
+        for (i, part) in parts.iter().enumerate() {
+            if i > 0 {
+                result.push_str(to);
+            }
+            result.push_str(part);
+        }
+
+        result
+    }
+
+    /// Substitute `{name}` placeholders from a map of values
+    ///
+    /// `{{` and `}}` produce literal braces. Placeholders whose name is not
+    /// in `vars` are left untouched, as are unbalanced braces. If the
+    /// template is not valid UTF-8 it is returned unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `vars` - Placeholder names and their replacement values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut vars = HashMap::new();
+    /// vars.insert("name".to_string(), String::from_str("world"));
+    ///
+    /// let template = String::from_str("Hello {name}");
+    /// assert_eq!(template.render(&vars).as_str().unwrap(), "Hello world");
+    ///
+    /// let template = String::from_str("{{literal}} {unknown} {name");
+    /// assert_eq!(template.render(&vars).as_str().unwrap(), "{literal} {unknown} {name");
+    /// ```
+    pub fn render(&self, vars: &std::collections::HashMap<std::string::String, String>) -> String {
+        let s = match self.as_str() {
+            Ok(s) => s,
+            Err(_) => return self.clone(),
+        };
+
+        let bytes = s.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut i = 0;
+
+        while i < bytes.len() {
+            match bytes[i] {
+                b'{' if bytes.get(i + 1) == Some(&b'{') => {
+                    out.push(b'{');
+                    i += 2;
+                }
+                b'}' if bytes.get(i + 1) == Some(&b'}') => {
+                    out.push(b'}');
+                    i += 2;
+                }
+                b'{' => {
+                    let rest = &s[i + 1..];
+                    match rest.find(['{', '}']) {
+                        Some(off) if rest.as_bytes()[off] == b'}' => {
+                            match vars.get(&rest[..off]) {
+                                Some(value) => out.extend_from_slice(&value.bytes),
+                                None => out.extend_from_slice(&bytes[i..i + off + 2]),
+                            }
+                            i += off + 2;
+                        }
+                        _ => {
+                            out.push(b'{');
+                            i += 1;
+                        }
+                    }
+                }
+                b => {
+                    out.push(b);
+                    i += 1;
+                }
+            }
+        }
+
+        String { bytes: out }
+    }
+
+    /// Replace only the first occurrence of a substring
+    ///
+    /// Returns an unchanged copy when `from` is empty or not found.
+    ///
+    /// # Arguments
+    ///
+    /// * `from` - Substring to replace
+    /// * `to` - Replacement string
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("a-b-c");
+    /// let replaced = s.replace_first(&String::from_str("-"), &String::from_str("+"));
+    /// assert_eq!(replaced.as_str().unwrap(), "a+b-c");
+    ///
+    /// let unchanged = s.replace_first(&String::from_str("x"), &String::from_str("+"));
+    /// assert_eq!(unchanged, s);
+    /// ```
+    pub fn replace_first(&self, from: &String, to: &String) -> String {
+        if from.is_empty() {
+            return self.clone();
+        }
+
+        match self.find(from) {
+            Some(pos) => {
+                let pos = pos as usize;
+                let mut bytes = Vec::with_capacity(self.bytes.len() + to.bytes.len());
+                bytes.extend_from_slice(&self.bytes[..pos]);
+                bytes.extend_from_slice(&to.bytes);
+                bytes.extend_from_slice(&self.bytes[pos + from.bytes.len()..]);
+                String { bytes }
+            }
+            None => self.clone(),
+        }
+    }
+
+    /// Check if the string starts with a prefix
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Prefix to check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello world");
+    /// assert!(s.starts_with(&String::from_str("hello")));
+    /// assert!(!s.starts_with(&String::from_str("world")));
+    /// ```
+    pub fn starts_with(&self, prefix: &String) -> bool {
+        self.bytes.starts_with(&prefix.bytes)
+    }
+
+    /// Check if the string ends with a suffix
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - Suffix to check
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let s = String::from_str("hello world");
+    /// assert!(s.ends_with(&String::from_str("world")));
     /// assert!(!s.ends_with(&String::from_str("hello")));
     /// ```
     pub fn ends_with(&self, suffix: &String) -> bool {
         self.bytes.ends_with(&suffix.bytes)
     }
+
+    /// Remove a prefix from the string
+    ///
+    /// # Arguments
+    ///
+    /// * `prefix` - Prefix to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The rest of the string if it starts with `prefix`
+    /// * `None` - If the string does not start with `prefix`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let hex = String::from_str("0xdeadbeef");
+    /// let prefix = String::from_str("0x");
+    /// assert_eq!(hex.strip_prefix(&prefix), Some(String::from_str("deadbeef")));
+    /// assert_eq!(String::from_str("deadbeef").strip_prefix(&prefix), None);
+    /// assert_eq!(prefix.strip_prefix(&prefix), Some(String::new()));
+    /// ```
+    pub fn strip_prefix(&self, prefix: &String) -> Option<String> {
+        self.bytes
+            .strip_prefix(prefix.bytes.as_slice())
+            .map(|rest| String {
+                bytes: rest.to_vec(),
+            })
+    }
+
+    /// Remove a suffix from the string
+    ///
+    /// # Arguments
+    ///
+    /// * `suffix` - Suffix to remove
+    ///
+    /// # Returns
+    ///
+    /// * `Some(String)` - The rest of the string if it ends with `suffix`
+    /// * `None` - If the string does not end with `suffix`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// let file = String::from_str("report.json");
+    /// let ext = String::from_str(".json");
+    /// assert_eq!(file.strip_suffix(&ext), Some(String::from_str("report")));
+    /// assert_eq!(String::from_str("report.txt").strip_suffix(&ext), None);
+    /// assert_eq!(ext.strip_suffix(&ext), Some(String::new()));
+    /// ```
+    pub fn strip_suffix(&self, suffix: &String) -> Option<String> {
+        self.bytes
+            .strip_suffix(suffix.bytes.as_slice())
+            .map(|rest| String {
+                bytes: rest.to_vec(),
+            })
+    }
+
+    /// Trim the string for numeric parsing, rejecting invalid and empty input
+    fn numeric_str(&self) -> Result<&str, std::string::String> {
+        let s = self.as_str()?.trim();
+        if s.is_empty() {
+            return Err("Cannot parse integer from empty string".to_string());
+        }
+        Ok(s)
+    }
+
+    /// Describe an integer parse failure
+    fn parse_error(s: &str, err: std::num::ParseIntError) -> std::string::String {
+        match err.kind() {
+            std::num::IntErrorKind::PosOverflow | std::num::IntErrorKind::NegOverflow => {
+                format!("Integer out of range: {}", s)
+            }
+            _ => format!("Invalid integer: {}", s),
+        }
+    }
+
+    /// Parse the string as an unsigned 64-bit integer
+    ///
+    /// Surrounding whitespace is ignored. Overflow is reported as an error,
+    /// never wrapped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u64)` - The parsed value
+    /// * `Err(String)` - If the string is empty, not a number, or out of range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// assert_eq!(String::from_str("42").parse_u64(), Ok(42));
+    /// assert_eq!(String::from_str("  42\n").parse_u64(), Ok(42));
+    /// assert!(String::from_str("18446744073709551616").parse_u64().is_err());
+    /// assert!(String::from_str("-1").parse_u64().is_err());
+    /// assert!(String::from_str("4x2").parse_u64().is_err());
+    /// assert!(String::from_str("   ").parse_u64().is_err());
+    /// ```
+    pub fn parse_u64(&self) -> Result<u64, std::string::String> {
+        let s = self.numeric_str()?;
+        s.parse::<u64>().map_err(|e| Self::parse_error(s, e))
+    }
+
+    /// Parse the string as a signed 64-bit integer
+    ///
+    /// Surrounding whitespace is ignored. Overflow is reported as an error,
+    /// never wrapped.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i64)` - The parsed value
+    /// * `Err(String)` - If the string is empty, not a number, or out of range
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// assert_eq!(String::from_str("-42").parse_i64(), Ok(-42));
+    /// assert_eq!(String::from_str(" 7 ").parse_i64(), Ok(7));
+    /// assert_eq!(String::from_str("-9223372036854775808").parse_i64(), Ok(i64::MIN));
+    /// assert!(String::from_str("9223372036854775808").parse_i64().is_err());
+    /// assert!(String::from_str("abc").parse_i64().is_err());
+    /// assert!(String::from_str("").parse_i64().is_err());
+    /// ```
+    pub fn parse_i64(&self) -> Result<i64, std::string::String> {
+        let s = self.numeric_str()?;
+        s.parse::<i64>().map_err(|e| Self::parse_error(s, e))
+    }
+
+    /// Collect the decimal digits of the string, ignoring spaces
+    ///
+    /// Returns `None` if any other character is present or there are no
+    /// digits at all.
+    fn luhn_digits(&self) -> Option<Vec<u8>> {
+        let mut digits = Vec::with_capacity(self.bytes.len());
+        for &b in &self.bytes {
+            match b {
+                b'0'..=b'9' => digits.push(b - b'0'),
+                b' ' => {}
+                _ => return None,
+            }
+        }
+
+        if digits.is_empty() {
+            None
+        } else {
+            Some(digits)
+        }
+    }
+
+    /// Compute the Luhn sum of digits, doubling every second digit from the
+    /// right starting with the rightmost when `double_last` is set
+    fn luhn_sum(digits: &[u8], double_last: bool) -> u64 {
+        digits
+            .iter()
+            .rev()
+            .enumerate()
+            .map(|(i, &d)| {
+                if (i % 2 == 0) == double_last {
+                    let doubled = d * 2;
+                    (if doubled > 9 { doubled - 9 } else { doubled }) as u64
+                } else {
+                    d as u64
+                }
+            })
+            .sum()
+    }
+
+    /// Check whether the string is a valid Luhn (mod 10) number
+    ///
+    /// Spaces are ignored. Any other non-digit character, or a string with
+    /// no digits, is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// assert!(String::from_str("4539 1488 0343 6467").is_valid_luhn());
+    /// assert!(String::from_str("79927398713").is_valid_luhn());
+    /// assert!(!String::from_str("79927398710").is_valid_luhn());
+    /// assert!(!String::from_str("7992-7398-713").is_valid_luhn());
+    /// ```
+    pub fn is_valid_luhn(&self) -> bool {
+        match self.luhn_digits() {
+            Some(digits) => Self::luhn_sum(&digits, false).is_multiple_of(10),
+            None => false,
+        }
+    }
+
+    /// Compute the Luhn check digit that would make the string valid
+    ///
+    /// Spaces are ignored.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(digit)` - The digit to append
+    /// * `None` - If the string contains a non-digit, non-space character
+    ///   or has no digits
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::String;
+    ///
+    /// assert_eq!(String::from_str("7992739871").luhn_check_digit(), Some(3));
+    /// assert_eq!(String::from_str("12a").luhn_check_digit(), None);
+    /// ```
+    pub fn luhn_check_digit(&self) -> Option<u8> {
+        let digits = self.luhn_digits()?;
+        let sum = Self::luhn_sum(&digits, true);
+        Some(((10 - sum % 10) % 10) as u8)
+    }
 }
 
 impl Default for String {
@@ -471,6 +1966,84 @@ impl Default for String {
     }
 }
 
+impl From<&str> for String {
+    fn from(s: &str) -> Self {
+        Self::from_str(s)
+    }
+}
+
+impl From<std::string::String> for String {
+    fn from(s: std::string::String) -> Self {
+        Self {
+            bytes: s.into_bytes(),
+        }
+    }
+}
+
+impl TryFrom<String> for std::string::String {
+    type Error = std::string::String;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        std::string::String::from_utf8(s.bytes).map_err(|_| "Invalid UTF-8".to_string())
+    }
+}
+
+/// Concatenate byte-wise, so non-UTF-8 content is preserved
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::String;
+///
+/// let greeting = String::from_str("hello") + &String::from_str(", ") + &String::from_str("world");
+/// assert_eq!(greeting.as_bytes(), b"hello, world");
+///
+/// let half = String::from_str("é").substring(0, 1).unwrap();
+/// let joined = half.clone() + &half;
+/// assert_eq!(joined.as_bytes(), &[0xC3, 0xC3]);
+/// ```
+impl std::ops::Add<&String> for String {
+    type Output = String;
+
+    fn add(mut self, rhs: &String) -> String {
+        self += rhs;
+        self
+    }
+}
+
+/// Append byte-wise, like `push_str`
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::String;
+///
+/// let mut s = String::from_str("a");
+/// s += &String::from_str("b");
+/// s += &String::new();
+/// s += &String::from_str("c");
+/// assert_eq!(s.as_bytes(), b"abc");
+/// ```
+impl std::ops::AddAssign<&String> for String {
+    fn add_assign(&mut self, rhs: &String) {
+        self.push_str(rhs);
+    }
+}
+
+impl Encode for String {
+    fn encode(&self, out: &mut Vec<u8>) {
+        (self.bytes.len() as u64).encode(out);
+        out.extend_from_slice(&self.bytes);
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, std::string::String> {
+        let len = encoding::decode_len(input)?;
+        Ok(Self {
+            bytes: encoding::take(input, len)?.to_vec(),
+        })
+    }
+}
+
 impl fmt::Display for String {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         if let Ok(s) = self.as_str() {
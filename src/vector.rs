@@ -7,6 +7,8 @@
 //! - Resource safety
 //! - Efficient memory management
 
+use crate::encoding::{self, Encode};
+use crate::math::{CheckedAdd, Rng};
 use serde::{Deserialize, Serialize};
 use std::fmt;
 
@@ -14,7 +16,59 @@ use std::fmt;
 ///
 /// Vectors are dynamic arrays that can grow and shrink at runtime.
 /// They enforce type safety and bounds checking.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+///
+/// # Examples
+///
+/// ```
+/// use quantum_stdlib::Vector;
+/// use std::collections::HashSet;
+///
+/// let mut set = HashSet::new();
+/// set.insert(Vector::from_vec(vec![1u64, 2u64]));
+/// set.insert(Vector::from_vec(vec![1u64, 2u64]));
+/// assert_eq!(set.len(), 1);
+/// ```
+///
+/// Vectors compare lexicographically, element by element, with a proper
+/// prefix ordered before any longer vector. This makes them usable as
+/// `BTreeMap` keys:
+///
+/// ```
+/// use quantum_stdlib::Vector;
+/// use std::collections::BTreeMap;
+///
+/// let v = |xs: &[u64]| Vector::from_vec(xs.to_vec());
+///
+/// assert!(v(&[1, 2]) < v(&[1, 3]));
+/// assert!(v(&[1]) < v(&[1, 0]));
+/// assert!(v(&[2]) > v(&[1, 9, 9]));
+/// assert_eq!(v(&[1, 2]).cmp(&v(&[1, 2])), std::cmp::Ordering::Equal);
+///
+/// let mut map = BTreeMap::new();
+/// map.insert(v(&[2]), "b");
+/// map.insert(v(&[1, 5]), "a");
+/// assert_eq!(map.keys().next(), Some(&v(&[1, 5])));
+/// ```
+///
+/// With the `borsh` feature, vectors encode as a little-endian `u32` length
+/// followed by the elements:
+///
+/// ```
+/// # #[cfg(feature = "borsh")]
+/// # {
+/// use quantum_stdlib::Vector;
+///
+/// let vec = Vector::from_vec(vec![1u32, 2u32]);
+/// let bytes = borsh::to_vec(&vec).unwrap();
+/// assert_eq!(bytes, [2, 0, 0, 0, 1, 0, 0, 0, 2, 0, 0, 0]);
+/// assert_eq!(borsh::from_slice::<Vector<u32>>(&bytes).unwrap(), vec);
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[cfg_attr(
+    feature = "borsh",
+    derive(borsh::BorshSerialize, borsh::BorshDeserialize)
+)]
 pub struct Vector<T> {
     elements: Vec<T>,
 }
@@ -87,6 +141,61 @@ impl<T> Vector<T> {
         self.elements.is_empty()
     }
 
+    /// Get the number of elements the vector can hold without reallocating
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec: Vector<u64> = Vector::with_capacity(10);
+    /// assert!(vec.capacity() >= 10);
+    /// ```
+    pub fn capacity(&self) -> u64 {
+        self.elements.capacity() as u64
+    }
+
+    /// Reserve capacity for at least `additional` more elements
+    ///
+    /// # Arguments
+    ///
+    /// * `additional` - Number of extra elements to make room for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec: Vector<u64> = Vector::new();
+    /// vec.push(1);
+    /// vec.reserve(10);
+    /// assert!(vec.capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: u64) {
+        self.elements.reserve(additional as usize);
+    }
+
+    /// Shrink the backing allocation as close to the length as possible
+    ///
+    /// Useful before persisting a vector that was built with a generous
+    /// `with_capacity`, since unused capacity still costs memory.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec: Vector<u64> = Vector::with_capacity(100);
+    /// vec.push(1);
+    /// vec.push(2);
+    /// vec.shrink_to_fit();
+    /// assert!(vec.capacity() >= vec.len());
+    /// assert!(vec.capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.elements.shrink_to_fit();
+    }
+
     /// Push an element to the end of the vector
     ///
     /// # Arguments
@@ -179,6 +288,56 @@ impl<T> Vector<T> {
         self.elements.get_mut(index as usize)
     }
 
+    /// Split off the first element
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&T, &[T]))` - The first element and the remaining elements
+    /// * `None` - If the vector is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64]);
+    /// assert_eq!(vec.split_first(), Some((&1u64, &[2u64, 3u64][..])));
+    ///
+    /// let single = Vector::from_vec(vec![1u64]);
+    /// assert_eq!(single.split_first(), Some((&1u64, &[][..])));
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.split_first(), None);
+    /// ```
+    pub fn split_first(&self) -> Option<(&T, &[T])> {
+        self.elements.split_first()
+    }
+
+    /// Split off the last element
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&T, &[T]))` - The last element and the preceding elements
+    /// * `None` - If the vector is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64]);
+    /// assert_eq!(vec.split_last(), Some((&3u64, &[1u64, 2u64][..])));
+    ///
+    /// let single = Vector::from_vec(vec![1u64]);
+    /// assert_eq!(single.split_last(), Some((&1u64, &[][..])));
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.split_last(), None);
+    /// ```
+    pub fn split_last(&self) -> Option<(&T, &[T])> {
+        self.elements.split_last()
+    }
+
     /// Set the value at the specified index
     ///
     /// # Arguments
@@ -255,6 +414,40 @@ impl<T> Vector<T> {
         Ok(())
     }
 
+    /// Move the element at the specified index to the front
+    ///
+    /// Elements before `index` shift back by one position.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - Index of the element to move
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - If the index is valid
+    /// * `Err(String)` - If the index is out of bounds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec = Vector::from_vec(vec![1u64, 2u64, 3u64, 4u64]);
+    /// vec.move_to_front(2).unwrap();
+    /// assert_eq!(vec.into_inner(), vec![3u64, 1u64, 2u64, 4u64]);
+    /// ```
+    pub fn move_to_front(&mut self, index: u64) -> Result<(), String> {
+        let idx = index as usize;
+        let len = self.elements.len();
+
+        if idx >= len {
+            return Err(format!("Index out of bounds: {} >= {}", index, len));
+        }
+
+        self.elements[..=idx].rotate_right(1);
+        Ok(())
+    }
+
     /// Remove an element at the specified index
     ///
     /// # Arguments
@@ -328,7 +521,19 @@ impl<T> Vector<T> {
         }
     }
 
-    /// Clear all elements from the vector
+    /// Insert a value into a sorted vector, keeping it sorted
+    ///
+    /// Binary-searches for the insertion point, so the vector must already
+    /// be sorted in ascending order. A value equal to existing elements is
+    /// inserted after all of them.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to insert
+    ///
+    /// # Returns
+    ///
+    /// The index at which the value was inserted
     ///
     /// # Examples
     ///
@@ -336,45 +541,68 @@ impl<T> Vector<T> {
     /// use quantum_stdlib::Vector;
     ///
     /// let mut vec = Vector::new();
-    /// vec.push(1u64);
-    /// vec.push(2u64);
-    /// vec.clear();
-    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.insert_sorted(5u64), 0);
+    /// assert_eq!(vec.insert_sorted(1u64), 0);
+    /// assert_eq!(vec.insert_sorted(9u64), 2);
+    /// assert_eq!(vec.insert_sorted(7u64), 2);
+    /// assert_eq!(vec.insert_sorted(5u64), 2); // after the existing 5
+    /// assert_eq!(vec.into_inner(), vec![1u64, 5u64, 5u64, 7u64, 9u64]);
     /// ```
-    pub fn clear(&mut self) {
-        self.elements.clear();
+    pub fn insert_sorted(&mut self, value: T) -> u64
+    where
+        T: Ord,
+    {
+        let idx = self.elements.partition_point(|elem| elem <= &value);
+        self.elements.insert(idx, value);
+        idx as u64
     }
 
-    /// Check if the vector contains a specific element
+    /// Remove a contiguous range of elements
+    ///
+    /// Removes the elements in `[start, end)`, shifting the remaining tail
+    /// down to close the gap.
     ///
     /// # Arguments
     ///
-    /// * `value` - Element to search for
+    /// * `start` - Index of the first element to remove
+    /// * `end` - Index one past the last element to remove
     ///
     /// # Returns
     ///
-    /// * `true` - If the element is found
-    /// * `false` - If the element is not found
+    /// * `Ok(Vec<T>)` - The removed elements, in order
+    /// * `Err(String)` - If `start > end` or `end` is out of bounds
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::Vector;
     ///
-    /// let mut vec = Vector::new();
-    /// vec.push(1u64);
-    /// vec.push(2u64);
-    /// assert!(vec.contains(&2u64));
-    /// assert!(!vec.contains(&3u64));
+    /// let mut vec = Vector::from_vec(vec![1u64, 2u64, 3u64, 4u64, 5u64]);
+    /// assert_eq!(vec.drain(1, 3).unwrap(), vec![2u64, 3u64]);
+    /// assert_eq!(vec.into_inner(), vec![1u64, 4u64, 5u64]);
+    ///
+    /// let mut vec = Vector::from_vec(vec![1u64, 2u64]);
+    /// assert_eq!(vec.drain(1, 1).unwrap(), Vec::<u64>::new());
+    /// assert_eq!(vec.drain(0, 2).unwrap(), vec![1u64, 2u64]);
+    /// assert!(vec.is_empty());
+    /// assert_eq!(vec.drain(0, 1).unwrap_err(), "Index out of bounds: 1 > 0");
+    /// assert_eq!(vec.drain(1, 0).unwrap_err(), "Invalid range: start 1 > end 0");
     /// ```
-    pub fn contains(&self, value: &T) -> bool
-    where
-        T: PartialEq,
-    {
-        self.elements.contains(value)
+    pub fn drain(&mut self, start: u64, end: u64) -> Result<Vec<T>, String> {
+        let len = crate::math::range_len(start, end)? as usize;
+        if end > self.len() {
+            return Err(format!(
+                "Index out of bounds: {} > {}",
+                end,
+                self.elements.len()
+            ));
+        }
+
+        let start = start as usize;
+        Ok(self.elements.drain(start..start + len).collect())
     }
 
-    /// Reverse the order of elements in the vector
+    /// Clear all elements from the vector
     ///
     /// # Examples
     ///
@@ -384,58 +612,109 @@ impl<T> Vector<T> {
     /// let mut vec = Vector::new();
     /// vec.push(1u64);
     /// vec.push(2u64);
-    /// vec.push(3u64);
-    /// vec.reverse();
-    /// assert_eq!(vec.get(0), Some(&3u64));
-    /// assert_eq!(vec.get(1), Some(&2u64));
-    /// assert_eq!(vec.get(2), Some(&1u64));
+    /// vec.clear();
+    /// assert!(vec.is_empty());
     /// ```
-    pub fn reverse(&mut self) {
-        self.elements.reverse();
+    pub fn clear(&mut self) {
+        self.elements.clear();
     }
 
-    /// Get an iterator over the vector elements
+    /// Overwrite every element with a clone of the given value
+    ///
+    /// Only existing elements are overwritten; the length is unchanged
+    /// and spare capacity is not filled.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Value to clone into every slot
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::Vector;
     ///
-    /// let mut vec = Vector::new();
-    /// vec.push(1u64);
-    /// vec.push(2u64);
-    /// vec.push(3u64);
-    ///
-    /// let sum: u64 = vec.iter().sum();
-    /// assert_eq!(sum, 6);
+    /// let mut vec = Vector::from_vec(vec![1u64, 2u64, 3u64]);
+    /// vec.fill(7u64);
+    /// assert_eq!(vec.len(), 3);
+    /// assert!(vec.iter().all(|&x| x == 7));
     /// ```
-    pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.elements.iter()
+    pub fn fill(&mut self, value: T)
+    where
+        T: Clone,
+    {
+        self.elements.fill(value);
     }
 
-    /// Get a mutable iterator over the vector elements
+    /// Overwrite every element with a value produced by a closure
+    ///
+    /// The closure is called once per existing element, in order. The
+    /// length is unchanged and spare capacity is not filled.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function producing the value for each slot
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::Vector;
     ///
-    /// let mut vec = Vector::new();
-    /// vec.push(1u64);
-    /// vec.push(2u64);
+    /// let mut vec = Vector::from_vec(vec![0u64; 3]);
+    /// let mut next = 10u64;
+    /// vec.fill_with(|| {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(vec.len(), 3);
+    /// assert_eq!(vec.into_inner(), vec![11u64, 12u64, 13u64]);
+    /// ```
+    pub fn fill_with<F>(&mut self, f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.elements.fill_with(f);
+    }
+
+    /// Keep only the elements for which a predicate returns true
     ///
-    /// for elem in vec.iter_mut() {
-    ///     *elem *= 2;
-    /// }
+    /// The predicate may mutate each element before deciding; mutations
+    /// to kept elements persist. Runs in a single pass and preserves the
+    /// order of the kept elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Predicate that may modify the element
+    ///
+    /// # Examples
     ///
-    /// assert_eq!(vec.get(0), Some(&2u64));
-    /// assert_eq!(vec.get(1), Some(&4u64));
     /// ```
-    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.elements.iter_mut()
+    /// use quantum_stdlib::Vector;
+    ///
+    /// // Decrement every TTL and drop the entries that expire
+    /// let mut ttls = Vector::from_vec(vec![3u64, 1u64, 2u64, 1u64]);
+    /// ttls.retain_mut(|ttl| {
+    ///     *ttl -= 1;
+    ///     *ttl > 0
+    /// });
+    /// assert_eq!(ttls.into_inner(), vec![2u64, 1u64]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, f: F)
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        self.elements.retain_mut(f);
     }
 
-    /// Convert the vector into its inner Vec
+    /// Check if the vector contains a specific element
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - Element to search for
+    ///
+    /// # Returns
+    ///
+    /// * `true` - If the element is found
+    /// * `false` - If the element is not found
     ///
     /// # Examples
     ///
@@ -445,40 +724,908 @@ impl<T> Vector<T> {
     /// let mut vec = Vector::new();
     /// vec.push(1u64);
     /// vec.push(2u64);
-    ///
-    /// let inner = vec.into_inner();
-    /// assert_eq!(inner, vec![1u64, 2u64]);
+    /// assert!(vec.contains(&2u64));
+    /// assert!(!vec.contains(&3u64));
     /// ```
-    pub fn into_inner(self) -> Vec<T> {
-        self.elements
+    pub fn contains(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.elements.contains(value)
     }
 
-    /// Create a vector from a Vec
+    /// Count the elements matching a predicate
     ///
     /// # Arguments
     ///
-    /// * `vec` - The Vec to convert
+    /// * `predicate` - Function to test each element
+    ///
+    /// # Returns
+    ///
+    /// Number of elements for which `predicate` returns true
     ///
     /// # Examples
     ///
     /// ```
     /// use quantum_stdlib::Vector;
     ///
-    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64]);
-    /// assert_eq!(vec.len(), 3);
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64, 4u64, 5u64]);
+    /// assert_eq!(vec.count(|x| x % 2 == 1), 3);
+    /// assert_eq!(vec.count(|_| true), 5);
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.count(|_| true), 0);
     /// ```
-    pub fn from_vec(vec: Vec<T>) -> Self {
-        Self { elements: vec }
+    pub fn count<F>(&self, mut predicate: F) -> u64
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.elements.iter().filter(|elem| predicate(elem)).count() as u64
     }
-}
 
-impl<T> Default for Vector<T> {
-    fn default() -> Self {
-        Self::new()
+    /// Check whether every element matches a predicate
+    ///
+    /// Stops at the first element that does not match.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Function to test each element
+    ///
+    /// # Returns
+    ///
+    /// True if `predicate` holds for all elements (always true when empty)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![2u64, 3u64, 4u64, 6u64]);
+    /// assert!(vec.all(|x| *x > 1));
+    ///
+    /// let mut calls = 0;
+    /// assert!(!vec.all(|x| {
+    ///     calls += 1;
+    ///     x % 2 == 0
+    /// }));
+    /// assert_eq!(calls, 2);
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert!(empty.all(|_| false));
+    /// ```
+    pub fn all<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.elements.iter().all(predicate)
     }
-}
 
-impl<T: fmt::Display> fmt::Display for Vector<T> {
+    /// Check whether any element matches a predicate
+    ///
+    /// Stops at the first element that matches.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Function to test each element
+    ///
+    /// # Returns
+    ///
+    /// True if `predicate` holds for at least one element (always false
+    /// when empty)
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 3u64, 4u64, 5u64]);
+    /// assert!(!vec.any(|x| *x > 10));
+    ///
+    /// let mut calls = 0;
+    /// assert!(vec.any(|x| {
+    ///     calls += 1;
+    ///     x % 2 == 0
+    /// }));
+    /// assert_eq!(calls, 3);
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert!(!empty.any(|_| true));
+    /// ```
+    pub fn any<F>(&self, predicate: F) -> bool
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.elements.iter().any(predicate)
+    }
+
+    /// Keep only the last element for each distinct key
+    ///
+    /// The surviving elements appear in the order of their last occurrence.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Function extracting the deduplication key of an element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let updates = Vector::from_vec(vec![("a", 1u64), ("b", 2u64), ("a", 3u64), ("c", 4u64)]);
+    /// let latest = updates.unique_keep_last(|&(name, _)| name);
+    /// assert_eq!(latest.into_inner(), vec![("b", 2u64), ("a", 3u64), ("c", 4u64)]);
+    /// ```
+    pub fn unique_keep_last<K, F>(&self, mut key: F) -> Vector<T>
+    where
+        T: Clone,
+        K: std::hash::Hash + Eq,
+        F: FnMut(&T) -> K,
+    {
+        let keys: Vec<K> = self.elements.iter().map(&mut key).collect();
+        let mut last_index = std::collections::HashMap::with_capacity(keys.len());
+        for (i, k) in keys.iter().enumerate() {
+            last_index.insert(k, i);
+        }
+
+        self.elements
+            .iter()
+            .zip(keys.iter())
+            .enumerate()
+            .filter(|(i, (_, k))| last_index.get(k) == Some(i))
+            .map(|(_, (elem, _))| elem.clone())
+            .collect()
+    }
+
+    /// Interleave the elements of two vectors
+    ///
+    /// Elements are taken alternately from `self` and `other`, starting with
+    /// `self`. Once the shorter vector is exhausted, the remainder of the
+    /// longer one is appended.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Vector to interleave with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let odds = Vector::from_vec(vec![1u64, 3u64, 5u64]);
+    /// let evens = Vector::from_vec(vec![2u64, 4u64]);
+    /// let merged = odds.interleave(&evens);
+    /// assert_eq!(merged.into_inner(), vec![1u64, 2u64, 3u64, 4u64, 5u64]);
+    /// ```
+    pub fn interleave(&self, other: &Vector<T>) -> Vector<T>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::with_capacity(self.elements.len() + other.elements.len());
+        let mut left = self.elements.iter();
+        let mut right = other.elements.iter();
+
+        loop {
+            match (left.next(), right.next()) {
+                (Some(a), Some(b)) => {
+                    result.push(a.clone());
+                    result.push(b.clone());
+                }
+                (Some(a), None) => {
+                    result.push(a.clone());
+                    result.extend(left.cloned());
+                    break;
+                }
+                (None, Some(b)) => {
+                    result.push(b.clone());
+                    result.extend(right.cloned());
+                    break;
+                }
+                (None, None) => break,
+            }
+        }
+
+        Self { elements: result }
+    }
+
+    /// Pair every element of this vector with every element of another
+    ///
+    /// The left element varies slowest. For inputs of length m and n the
+    /// result has m * n pairs.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - Vector supplying the right-hand elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let numbers = Vector::from_vec(vec![1u64, 2u64]);
+    /// let letters = Vector::from_vec(vec!["a", "b"]);
+    /// let pairs = numbers.cartesian_product(&letters);
+    /// assert_eq!(
+    ///     pairs.into_inner(),
+    ///     vec![(1u64, "a"), (1u64, "b"), (2u64, "a"), (2u64, "b")]
+    /// );
+    /// ```
+    pub fn cartesian_product<U>(&self, other: &Vector<U>) -> Vector<(T, U)>
+    where
+        T: Clone,
+        U: Clone,
+    {
+        // m * n can overflow usize for huge inputs; skip pre-sizing then.
+        let capacity = self
+            .elements
+            .len()
+            .checked_mul(other.elements.len())
+            .unwrap_or(0);
+        let mut result = Vec::with_capacity(capacity);
+        for a in &self.elements {
+            for b in &other.elements {
+                result.push((a.clone(), b.clone()));
+            }
+        }
+        Vector { elements: result }
+    }
+
+    /// Split the vector into runs separated by elements matching a predicate
+    ///
+    /// Separator elements are discarded. Adjacent separators, or a separator
+    /// at either end, produce empty runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `is_sep` - Predicate identifying separator elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 0, 2, 3, 0, 4]);
+    /// let runs = vec.split_when(|&x| x == 0);
+    /// assert_eq!(
+    ///     runs,
+    ///     Vector::from_vec(vec![
+    ///         Vector::from_vec(vec![1u64]),
+    ///         Vector::from_vec(vec![2u64, 3]),
+    ///         Vector::from_vec(vec![4u64]),
+    ///     ])
+    /// );
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 0, 0, 2]);
+    /// let runs = vec.split_when(|&x| x == 0);
+    /// assert_eq!(runs.len(), 3);
+    /// assert!(runs.get(1).unwrap().is_empty());
+    /// ```
+    pub fn split_when<F>(&self, is_sep: F) -> Vector<Vector<T>>
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        self.elements
+            .split(is_sep)
+            .map(|run| Vector::from_vec(run.to_vec()))
+            .collect()
+    }
+
+    /// Split the elements by a predicate
+    ///
+    /// Each element is tested once; relative order is preserved in both
+    /// results.
+    ///
+    /// # Arguments
+    ///
+    /// * `predicate` - Function deciding which side an element goes to
+    ///
+    /// # Returns
+    ///
+    /// `(matching, non_matching)` as two new vectors
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64, 4u64, 5u64]);
+    ///
+    /// let (even, odd) = vec.partition(|x| x % 2 == 0);
+    /// assert_eq!(even.into_inner(), vec![2u64, 4u64]);
+    /// assert_eq!(odd.into_inner(), vec![1u64, 3u64, 5u64]);
+    ///
+    /// let (all, none) = vec.partition(|_| true);
+    /// assert_eq!(all, vec);
+    /// assert!(none.is_empty());
+    ///
+    /// let (none, all) = vec.partition(|_| false);
+    /// assert!(none.is_empty());
+    /// assert_eq!(all, vec);
+    /// ```
+    pub fn partition<F>(&self, mut predicate: F) -> (Vector<T>, Vector<T>)
+    where
+        T: Clone,
+        F: FnMut(&T) -> bool,
+    {
+        let mut matching = Vector::new();
+        let mut non_matching = Vector::new();
+        for elem in &self.elements {
+            if predicate(elem) {
+                matching.push(elem.clone());
+            } else {
+                non_matching.push(elem.clone());
+            }
+        }
+        (matching, non_matching)
+    }
+
+    /// Reverse the order of elements in the vector
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(1u64);
+    /// vec.push(2u64);
+    /// vec.push(3u64);
+    /// vec.reverse();
+    /// assert_eq!(vec.get(0), Some(&3u64));
+    /// assert_eq!(vec.get(1), Some(&2u64));
+    /// assert_eq!(vec.get(2), Some(&1u64));
+    /// ```
+    pub fn reverse(&mut self) {
+        self.elements.reverse();
+    }
+
+    /// Sort the elements in ascending order without allocating
+    ///
+    /// Faster than a stable sort, but equal elements may be reordered
+    /// relative to each other.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Rng, Vector};
+    ///
+    /// let mut rng = Rng::new(2024);
+    /// let values: Vec<u64> = (0..10_000).map(|_| rng.next_range(0, 1000)).collect();
+    ///
+    /// let mut vec = Vector::from_vec(values.clone());
+    /// vec.sort_unstable();
+    ///
+    /// let mut expected = values;
+    /// expected.sort();
+    /// assert_eq!(vec.into_inner(), expected);
+    /// ```
+    pub fn sort_unstable(&mut self)
+    where
+        T: Ord,
+    {
+        self.elements.sort_unstable();
+    }
+
+    /// Sort the elements with a comparator without allocating
+    ///
+    /// Faster than a stable sort, but elements the comparator treats as
+    /// equal may be reordered relative to each other.
+    ///
+    /// # Arguments
+    ///
+    /// * `compare` - Function defining the order
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Rng, Vector};
+    ///
+    /// let mut rng = Rng::new(7);
+    /// let values: Vec<u64> = (0..10_000).map(|_| rng.next_u64()).collect();
+    ///
+    /// let mut vec = Vector::from_vec(values.clone());
+    /// vec.sort_unstable_by(|a, b| b.cmp(a));
+    ///
+    /// let mut expected = values;
+    /// expected.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(vec.into_inner(), expected);
+    /// ```
+    pub fn sort_unstable_by<F>(&mut self, compare: F)
+    where
+        F: FnMut(&T, &T) -> std::cmp::Ordering,
+    {
+        self.elements.sort_unstable_by(compare);
+    }
+
+    /// Shuffle the elements in place
+    ///
+    /// Performs a Fisher-Yates shuffle driven by `rng`, so every permutation
+    /// is equally likely and the same seed always produces the same
+    /// permutation on every platform.
+    ///
+    /// # Arguments
+    ///
+    /// * `rng` - Deterministic generator supplying the randomness
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Rng, Vector};
+    ///
+    /// let mut vec = Vector::from_vec((0u64..8).collect());
+    /// vec.shuffle(&mut Rng::new(42));
+    /// assert_eq!(vec.as_slice(), &[3, 1, 6, 2, 4, 0, 7, 5]);
+    ///
+    /// let mut sorted = vec.into_inner();
+    /// sorted.sort();
+    /// assert_eq!(sorted, (0u64..8).collect::<Vec<_>>());
+    /// ```
+    pub fn shuffle(&mut self, rng: &mut Rng) {
+        for i in (1..self.elements.len()).rev() {
+            let j = rng.next_range(0, i as u64 + 1) as usize;
+            self.elements.swap(i, j);
+        }
+    }
+
+    /// Get a reference to the largest element
+    ///
+    /// If several elements are equally maximum, the last one is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - The largest element if the vector is not empty
+    /// * `None` - If the vector is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.max_element(), None);
+    ///
+    /// let single = Vector::from_vec(vec![42u64]);
+    /// assert_eq!(single.max_element(), Some(&42u64));
+    ///
+    /// let vec = Vector::from_vec(vec![3u64, 7u64, 1u64, 7u64]);
+    /// assert_eq!(vec.max_element(), Some(&7u64));
+    /// ```
+    pub fn max_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.elements.iter().max()
+    }
+
+    /// Get a reference to the smallest element
+    ///
+    /// If several elements are equally minimum, the first one is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(&T)` - The smallest element if the vector is not empty
+    /// * `None` - If the vector is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.min_element(), None);
+    ///
+    /// let single = Vector::from_vec(vec![42u64]);
+    /// assert_eq!(single.min_element(), Some(&42u64));
+    ///
+    /// let vec = Vector::from_vec(vec![3u64, 7u64, 1u64, 7u64]);
+    /// assert_eq!(vec.min_element(), Some(&1u64));
+    /// ```
+    pub fn min_element(&self) -> Option<&T>
+    where
+        T: Ord,
+    {
+        self.elements.iter().min()
+    }
+
+    /// Get references to the smallest and largest elements in a single pass
+    ///
+    /// Elements are compared in pairs, so at most three comparisons are made
+    /// for every two elements. Ties are resolved the same way as `min_element`
+    /// and `max_element`: the first minimum and the last maximum are returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Some((&T, &T))` - The `(min, max)` pair if the vector is not empty
+    /// * `None` - If the vector is empty
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![3u64, 1u64, 4u64, 1u64, 5u64]);
+    /// assert_eq!(vec.min_max(), Some((&1u64, &5u64)));
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.min_max(), None);
+    /// ```
+    pub fn min_max(&self) -> Option<(&T, &T)>
+    where
+        T: Ord,
+    {
+        let mut iter = self.elements.iter();
+        let first = iter.next()?;
+        let (mut min, mut max) = (first, first);
+
+        loop {
+            match (iter.next(), iter.next()) {
+                (Some(a), Some(b)) => {
+                    let (lo, hi) = if b < a { (b, a) } else { (a, b) };
+                    if lo < min {
+                        min = lo;
+                    }
+                    if hi >= max {
+                        max = hi;
+                    }
+                }
+                (Some(a), None) => {
+                    if a < min {
+                        min = a;
+                    } else if a >= max {
+                        max = a;
+                    }
+                    break;
+                }
+                _ => break,
+            }
+        }
+
+        Some((min, max))
+    }
+
+    /// Sum all elements of the vector
+    ///
+    /// An empty vector sums to the additive identity of `T`.
+    ///
+    /// Overflow is not detected in release builds, where the total silently
+    /// wraps. Use [`Vector::checked_sum`] for balances and other values that
+    /// must never wrap.
+    ///
+    /// # Panics
+    ///
+    /// Panics on overflow in debug builds, like the `+` operator
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.sum(), 0);
+    ///
+    /// let single = Vector::from_vec(vec![42u64]);
+    /// assert_eq!(single.sum(), 42);
+    ///
+    /// let vec = Vector::from_vec(vec![3u64, 7u64, 1u64, 7u64]);
+    /// assert_eq!(vec.sum(), 18);
+    /// ```
+    pub fn sum(&self) -> T
+    where
+        T: Copy + std::iter::Sum<T>,
+    {
+        self.elements.iter().copied().sum()
+    }
+
+    /// Sum all elements of the vector, detecting overflow
+    ///
+    /// An empty vector sums to zero.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(sum)` - The total of all elements
+    /// * `None` - If the running total would overflow `T`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.checked_sum(), Some(0));
+    ///
+    /// let vec = Vector::from_vec(vec![3u64, 7u64, 1u64, 7u64]);
+    /// assert_eq!(vec.checked_sum(), Some(18));
+    ///
+    /// let overflowing = Vector::from_vec(vec![u64::MAX, 1u64]);
+    /// assert_eq!(overflowing.checked_sum(), None);
+    /// ```
+    pub fn checked_sum(&self) -> Option<T>
+    where
+        T: Copy + Default + CheckedAdd,
+    {
+        self.elements
+            .iter()
+            .try_fold(T::default(), |acc, &x| acc.checked_add(x))
+    }
+
+    /// Reduce the elements to a single value
+    ///
+    /// Applies `f` to an accumulator and each element in order, starting
+    /// from `init`.
+    ///
+    /// # Arguments
+    ///
+    /// * `init` - Initial accumulator value
+    /// * `f` - Function combining the accumulator with an element
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64, 4u64]);
+    /// assert_eq!(vec.fold(0u64, |acc, x| acc + x), 10);
+    /// assert_eq!(vec.fold(String::new(), |acc, x| acc + &x.to_string()), "1234");
+    ///
+    /// let empty: Vector<u64> = Vector::new();
+    /// assert_eq!(empty.fold(7u64, |acc, x| acc + x), 7);
+    /// ```
+    pub fn fold<B, F>(&self, init: B, f: F) -> B
+    where
+        F: FnMut(B, &T) -> B,
+    {
+        self.elements.iter().fold(init, f)
+    }
+
+    /// Call a function on each element in order
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - Function to call
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64]);
+    /// let mut seen = Vec::new();
+    /// vec.for_each(|&x| seen.push(x * 10));
+    /// assert_eq!(seen, vec![10u64, 20u64, 30u64]);
+    /// ```
+    pub fn for_each<F>(&self, f: F)
+    where
+        F: FnMut(&T),
+    {
+        self.elements.iter().for_each(f);
+    }
+
+    /// Get an iterator over the vector elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(1u64);
+    /// vec.push(2u64);
+    /// vec.push(3u64);
+    ///
+    /// let sum: u64 = vec.iter().sum();
+    /// assert_eq!(sum, 6);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.elements.iter()
+    }
+
+    /// Get a mutable iterator over the vector elements
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(1u64);
+    /// vec.push(2u64);
+    ///
+    /// for elem in vec.iter_mut() {
+    ///     *elem *= 2;
+    /// }
+    ///
+    /// assert_eq!(vec.get(0), Some(&2u64));
+    /// assert_eq!(vec.get(1), Some(&4u64));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.elements.iter_mut()
+    }
+
+    /// Borrow the elements as a slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![3u64, 1u64, 2u64]);
+    /// assert_eq!(vec.as_slice(), &[3u64, 1u64, 2u64]);
+    /// assert_eq!(vec.as_slice().binary_search(&7), Err(3));
+    /// ```
+    pub fn as_slice(&self) -> &[T] {
+        &self.elements
+    }
+
+    /// Borrow the elements as a mutable slice
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec = Vector::from_vec(vec![3u64, 1u64, 2u64]);
+    /// vec.as_mut_slice().sort();
+    /// vec.as_mut_slice()[0] = 10;
+    /// assert_eq!(vec.into_inner(), vec![10u64, 2u64, 3u64]);
+    /// ```
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.elements
+    }
+
+    /// Convert the vector into its inner Vec
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let mut vec = Vector::new();
+    /// vec.push(1u64);
+    /// vec.push(2u64);
+    ///
+    /// let inner = vec.into_inner();
+    /// assert_eq!(inner, vec![1u64, 2u64]);
+    /// ```
+    pub fn into_inner(self) -> Vec<T> {
+        self.elements
+    }
+
+    /// Create a vector from a Vec
+    ///
+    /// # Arguments
+    ///
+    /// * `vec` - The Vec to convert
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u64, 2u64, 3u64]);
+    /// assert_eq!(vec.len(), 3);
+    /// ```
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        Self { elements: vec }
+    }
+}
+
+impl<T: Clone> Vector<crate::option::Option<T>> {
+    /// Collect the values of all `Some` elements, dropping `None`s
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{Option, Vector};
+    ///
+    /// let vec = Vector::from_vec(vec![Option::Some(1u64), Option::None, Option::Some(3u64)]);
+    /// assert_eq!(vec.flatten_options().into_inner(), vec![1u64, 3u64]);
+    /// ```
+    pub fn flatten_options(&self) -> Vector<T> {
+        self.elements
+            .iter()
+            .filter_map(|elem| match elem {
+                crate::option::Option::Some(v) => Some(v.clone()),
+                crate::option::Option::None => None,
+            })
+            .collect()
+    }
+}
+
+impl<T> Vector<Vector<T>> {
+    /// Concatenate the inner vectors into a single vector
+    ///
+    /// Inner vectors are appended in order, and the result is allocated
+    /// once using the summed inner lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let nested = Vector::from_vec(vec![
+    ///     Vector::from_vec(vec![1u64, 2u64]),
+    ///     Vector::new(),
+    ///     Vector::from_vec(vec![3u64]),
+    ///     Vector::from_vec(vec![4u64, 5u64, 6u64]),
+    /// ]);
+    /// let flat = nested.flatten();
+    /// assert_eq!(flat.into_inner(), vec![1u64, 2u64, 3u64, 4u64, 5u64, 6u64]);
+    ///
+    /// let empty: Vector<Vector<u64>> = Vector::from_vec(vec![Vector::new()]);
+    /// assert!(empty.flatten().is_empty());
+    /// ```
+    pub fn flatten(self) -> Vector<T> {
+        let total = self.elements.iter().map(|inner| inner.elements.len()).sum();
+        let mut elements = Vec::with_capacity(total);
+        for inner in self.elements {
+            elements.extend(inner.elements);
+        }
+        Vector { elements }
+    }
+}
+
+impl<T: Encode> Vector<T> {
+    /// Encode the vector in the canonical byte format
+    ///
+    /// The encoding is a little-endian `u64` element count followed by each
+    /// element's own encoding, as described in [`crate::encoding`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::Vector;
+    ///
+    /// let vec = Vector::from_vec(vec![1u16, 2u16]);
+    /// assert_eq!(vec.to_canonical_bytes(), [2, 0, 0, 0, 0, 0, 0, 0, 1, 0, 2, 0]);
+    ///
+    /// let same = Vector::from_vec(vec![1u16, 2u16]);
+    /// assert_eq!(vec.to_canonical_bytes(), same.to_canonical_bytes());
+    /// ```
+    pub fn to_canonical_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        self.encode(&mut out);
+        out
+    }
+
+    /// Decode a vector from the canonical byte format
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - Canonically encoded vector
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vector)` - The decoded vector
+    /// * `Err(String)` - If `bytes` is truncated, malformed or has trailing data
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use quantum_stdlib::{String, Vector};
+    ///
+    /// let names = Vector::from_vec(vec![String::from_str("a"), String::from_str("bc")]);
+    /// let bytes = names.to_canonical_bytes();
+    /// assert_eq!(Vector::from_canonical_bytes(&bytes), Ok(names));
+    ///
+    /// let nested = Vector::from_vec(vec![Vector::from_vec(vec![1u64]), Vector::new()]);
+    /// let bytes = nested.to_canonical_bytes();
+    /// assert_eq!(Vector::from_canonical_bytes(&bytes), Ok(nested));
+    ///
+    /// assert!(Vector::<u64>::from_canonical_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 7]).is_err());
+    /// assert!(Vector::<u8>::from_canonical_bytes(&[1, 0, 0, 0, 0, 0, 0, 0, 7, 8]).is_err());
+    /// ```
+    pub fn from_canonical_bytes(bytes: &[u8]) -> Result<Self, String> {
+        encoding::decode_exact(bytes)
+    }
+}
+
+impl<T> Default for Vector<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Vector<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "[")?;
         for (i, elem) in self.elements.iter().enumerate() {
@@ -498,3 +1645,22 @@ impl<T> FromIterator<T> for Vector<T> {
         }
     }
 }
+
+impl<T: Encode> Encode for Vector<T> {
+    fn encode(&self, out: &mut Vec<u8>) {
+        self.len().encode(out);
+        for elem in &self.elements {
+            elem.encode(out);
+        }
+    }
+
+    fn decode(input: &mut &[u8]) -> Result<Self, String> {
+        let len = encoding::decode_len(input)?;
+        // Grow as elements decode instead of trusting the length prefix
+        let mut elements = Vec::new();
+        for _ in 0..len {
+            elements.push(T::decode(input)?);
+        }
+        Ok(Self { elements })
+    }
+}